@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use genrs_lib::{
+    encode_key, generate_key, generate_key_with_rng, generate_uuid_v4_batch, EncodingFormat,
+};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use uuid::Uuid;
+
+const KEY_LENGTHS: [usize; 4] = [16, 32, 64, 1024];
+const UUID_BATCH_SIZE: usize = 10_000;
+
+fn bench_generate_key(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate_key");
+    for length in KEY_LENGTHS {
+        group.throughput(Throughput::Bytes(length as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(length),
+            &length,
+            |b, &length| b.iter(|| generate_key(black_box(length))),
+        );
+    }
+    group.finish();
+}
+
+fn bench_encode_key(c: &mut Criterion) {
+    // Encode a fixed key from a seeded RNG so every format sees the same input on every run.
+    let key = generate_key_with_rng(32, &mut ChaCha20Rng::seed_from_u64(0));
+
+    let mut group = c.benchmark_group("encode_key");
+    group.throughput(Throughput::Bytes(key.len() as u64));
+    for format in EncodingFormat::ALL {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format),
+            &format,
+            |b, &format| b.iter(|| encode_key(black_box(&key), format).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn bench_uuid_v4_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uuid_v4");
+    group.throughput(Throughput::Elements(UUID_BATCH_SIZE as u64));
+    group.bench_function("new_v4_loop", |b| {
+        b.iter(|| {
+            (0..UUID_BATCH_SIZE)
+                .map(|_| Uuid::new_v4())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| generate_uuid_v4_batch(black_box(UUID_BATCH_SIZE)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_generate_key,
+    bench_encode_key,
+    bench_uuid_v4_batch
+);
+criterion_main!(benches);
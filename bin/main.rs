@@ -1,22 +1,98 @@
-use clap::{crate_authors, crate_version, value_parser, Arg, Command};
-use genrs_lib::{encode_key, generate_key, generate_uuid, EncodingFormat, UuidVersion};
+use std::fs::OpenOptions;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+
+use clap::builder::RangedU64ValueParser;
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{crate_authors, crate_version, value_parser, Arg, ArgAction, ArgMatches, Command};
+use clap_complete::{generate, Shell};
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Input, Select};
+#[cfg(feature = "kdf")]
+use genrs_lib::{derive_key, Kdf};
+use genrs_lib::{
+    decode_key, decorate, detect_encoding, deterministic_uuid_v4, dotenv_quote, encode_array_literal, encode_bech32,
+    encode_hex_ct, encode_key, encode_key_uppercase, encode_pem, entropy_available, entropy_bits, format_hex,
+    format_uuid, format_uuid_uppercase, generate_key, generate_key_from_source, generate_key_with_rng,
+    generate_license_key, generate_license_key_with_rng, generate_mnemonic, generate_mnemonic_with_rng,
+    generate_passphrase, generate_passphrase_with_rng, generate_password, generate_password_with_rng,
+    generate_policy_password, generate_policy_password_with_rng, generate_uuids, key_fingerprint, parse_namespace,
+    passphrase_entropy_bits, shell_quote, strength_warning, totp_uri, uuid_timestamp, ArrayConfig, ArrayLanguage,
+    Charset, CustomBase64, EncodingFormat, EntropySource, HexConfig, KeyPreset, MnemonicLength, PasswordPolicy,
+    UuidFormat, UuidVersion, DEFAULT_BECH32_HRP, DEFAULT_PEM_LABEL, MAX_KEY_LENGTH, TOTP_SECRET_LENGTH,
+};
+use qrcode::render::unicode::Dense1x2;
+use qrcode::types::QrError;
+use qrcode::QrCode;
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use serde_json::{json, Value};
 use uuid::Uuid;
 
-/// Enum for common key presets
-pub enum KeyPreset {
-    Aes128,
-    Aes192,
-    Aes256,
-    HmacSha256,
-    HmacSha512,
-    Jwt256,
-    Jwt512,
-    ApiKey128,
-    ApiKey256,
+/// A generated value along with its human-readable description and JSON representation.
+struct Generated {
+    /// Description printed before the value, e.g. `Generated Key (hex format, 32 bytes)`.
+    description: String,
+    /// The generated value itself, e.g. the encoded key or the formatted UUID.
+    value: String,
+    /// Structured representation used for `--output json`.
+    json: Value,
 }
 
-fn main() {
-    let matches = Command::new("Key Generator")
+/// Names of the encoding formats accepted by `--format`, `--from` and `--to`.
+const ENCODING_FORMATS: [&str; 12] = [
+    "hex",
+    "base64",
+    "base64nopad",
+    "base64url",
+    "base64urlnopad",
+    "base32",
+    "base32crockford",
+    "base32crockfordcheck",
+    "base58",
+    "ascii85",
+    "pem",
+    "bech32",
+];
+
+/// Every mode with the description of its subcommand, in the order they are listed by `--help`.
+const MODES: [(&str, &str); 10] = [
+    ("key", "Generates random keys (default)"),
+    ("uuid", "Generates UUIDs"),
+    ("password", "Generates passwords"),
+    ("mnemonic", "Generates BIP39 mnemonic phrases"),
+    ("passphrase", "Generates diceware passphrases"),
+    ("license", "Generates license-key style keys"),
+    ("derive", "Derives a key from a passphrase"),
+    ("convert", "Re-encodes a key in another format"),
+    ("totp", "Generates a TOTP secret and provisioning URI"),
+    ("validate", "Validates a UUID"),
+];
+
+/// Builds the command-line interface, shared by argument parsing and completion generation.
+///
+/// Every mode is a subcommand with the arguments that apply to it. The mode arguments are also
+/// accepted, hidden, by the top-level command, where the deprecated `--mode` selects the mode;
+/// `main` rejects them there when a subcommand is given.
+fn cli() -> Command {
+    let mode_args = mode_args();
+    let subcommands = MODES.map(|(mode, about)| {
+        let args = mode_args
+            .iter()
+            .filter(|arg| arg_modes(arg.get_id().as_str()).contains(&mode))
+            .map(|arg| match required_in(arg.get_id().as_str()) {
+                Some(required) if required == mode => arg.clone().required(true),
+                _ => arg.clone(),
+            });
+        Command::new(mode).about(about).args(args)
+    });
+    let top_level_args = mode_args.into_iter().map(|arg| match required_in(arg.get_id().as_str()) {
+        Some(mode) => arg.required_if_eq("mode", mode).hide(true),
+        None => arg.hide(true),
+    });
+
+    Command::new("Key Generator")
         .version(crate_version!())
         .author(crate_authors!("\n"))
         .about("Generates random keys, UUIDs, and encodes them in different formats or presets")
@@ -24,144 +100,1636 @@ fn main() {
             "{name} ({version}) \n- {about-with-newline}\n\
            {all-args}\n\n{author}",
         )
+        .subcommands(subcommands)
         .arg(
             Arg::new("mode")
                 .short('m')
                 .long("mode")
                 .value_name("MODE")
-                .value_parser(["key", "uuid"])
+                .value_parser(["key", "uuid", "password", "mnemonic", "passphrase", "license", "derive", "convert", "totp", "validate"])
                 .default_value("key")
-                .help("Specifies the mode: 'key' for key generation, 'uuid' for UUID generation"),
-        )
-        .arg(
-            Arg::new("preset")
-                .short('p')
-                .long("preset")
-                .value_name("PRESET")
-                .value_parser(["aes128", "aes192", "aes256", "hmac256", "hmac512", "jwt256", "jwt512", "apikey128", "apikey256"])
-                .help("Specifies a preset for common keys: aes128, aes192, aes256, hmac256, hmac512, jwt256, jwt512, apikey128, apikey256"),
-        )
-        .arg(
-            Arg::new("format")
-                .short('f')
-                .long("format")
-                .value_name("FORMAT")
-                .value_parser(["hex", "base64"])
-                .default_value("hex")
-                .help("Specifies the encoding format for keys: hex or base64 (only for key mode)"),
-        )
-        .arg(
-            Arg::new("length")
-                .short('l')
-                .long("length")
-                .value_name("LENGTH")
-                .value_parser(value_parser!(usize))
-                .default_value("32")
-                .help("Specifies the key length in bytes (default: 32 bytes / 256 bits). Ignored if preset is used."),
+                .help("Deprecated: use the subcommand of the same name instead, e.g. 'genrs uuid'. Specifies the mode: 'key' for key generation, 'uuid' for UUID generation, 'password' for password generation, 'mnemonic' for BIP39 mnemonic generation, 'passphrase' for diceware passphrase generation, 'license' for license-key style keys, 'derive' for deriving a key from a passphrase, 'convert' for re-encoding a key, 'totp' for a TOTP provisioning URI, 'validate' for UUID validation"),
         )
+        .args(top_level_args)
+        .args(global_args().into_iter().map(|arg| arg.global(true)))
         .arg(
-            Arg::new("uuid_version")
-                .short('u')
-                .long("uuid-version")
-                .value_name("UUID_VERSION")
-                .value_parser(["v1", "v3", "v4", "v5"])
-                .default_value("v4")
-                .help("Specifies the UUID version (only for UUID mode)"),
+            Arg::new("build_info")
+                .long("build-info")
+                .action(ArgAction::SetTrue)
+                .help("Prints the versions of the cryptographic dependencies and the entropy source, then exits"),
         )
         .arg(
-            Arg::new("namespace")
-                .short('n')
-                .long("namespace")
-                .value_name("NAMESPACE")
-                .help("Specifies the UUID namespace (only for UUID V3 or V5)"),
+            Arg::new("list_presets")
+                .long("list-presets")
+                .action(ArgAction::SetTrue)
+                .help("Lists the available key presets with their descriptions and byte lengths, then exits"),
         )
         .arg(
-            Arg::new("name")
-                .short('N')
-                .long("name")
-                .value_name("NAME")
-                .help("Specifies the name for UUID V3 or V5"),
+            Arg::new("generate_completions")
+                .long("generate-completions")
+                .value_name("SHELL")
+                .value_parser(value_parser!(Shell))
+                .hide(true)
+                .help("Prints a completion script for the given shell to stdout"),
         )
-        .get_matches();
+}
+
+/// Arguments that only apply to some modes, as listed by `arg_modes`.
+fn mode_args() -> Vec<Arg> {
+    vec![
+        Arg::new("issuer")
+            .long("issuer")
+            .value_name("ISSUER")
+            .help("Specifies the issuer shown by authenticator apps, e.g. the service name (only for totp mode)"),
+        Arg::new("account")
+            .long("account")
+            .value_name("ACCOUNT")
+            .help("Specifies the account name, e.g. the user's email address (only for totp mode)"),
+        Arg::new("from")
+            .long("from")
+            .value_name("FORMAT")
+            .value_parser(ENCODING_FORMATS)
+            .help("Specifies the encoding format of the input; detected automatically if omitted (only for convert mode)"),
+        Arg::new("to")
+            .long("to")
+            .value_name("FORMAT")
+            .value_parser(ENCODING_FORMATS)
+            .help("Specifies the encoding format of the output (only for convert mode)"),
+        Arg::new("passphrase")
+            .long("passphrase")
+            .value_name("PASSPHRASE")
+            .help("Specifies the passphrase to derive the key from (only for derive mode). Note that command-line arguments may be visible to other users"),
+        Arg::new("salt")
+            .long("salt")
+            .value_name("SALT")
+            .help("Specifies the salt, at least 8 bytes (only for derive mode)"),
+        Arg::new("kdf")
+            .long("kdf")
+            .value_name("KDF")
+            .value_parser(["argon2id", "pbkdf2"])
+            .default_value("argon2id")
+            .help("Specifies the key derivation function: argon2id or pbkdf2 (only for derive mode)"),
+        Arg::new("preset")
+            .short('p')
+            .long("preset")
+            .value_name("PRESET")
+            .value_parser(["aes128", "aes192", "aes256", "hmac256", "hmac512", "jwt256", "jwt512", "apikey128", "apikey256", "chacha20", "xchacha20nonce", "aesgcmnonce", "ed25519seed"])
+            .help("Specifies a preset for common keys: aes128, aes192, aes256, hmac256, hmac512, jwt256, jwt512, apikey128, apikey256, chacha20, xchacha20nonce, aesgcmnonce, ed25519seed"),
+        Arg::new("format")
+            .short('f')
+            .long("format")
+            .value_name("FORMAT")
+            .value_parser(ENCODING_FORMATS)
+            .value_delimiter(',')
+            .default_value("hex")
+            .help("Specifies the encoding format for keys: hex, base64, base64nopad, base64url, base64urlnopad, base32, base32crockford, base32crockfordcheck, base58, ascii85, pem or bech32; a comma-separated list prints the same key in each format (in UUID mode, encodes the 16 UUID bytes instead of the canonical string)"),
+        Arg::new("pem_label")
+            .long("pem-label")
+            .value_name("LABEL")
+            .default_value(DEFAULT_PEM_LABEL)
+            .help("Specifies the label of the PEM block, e.g. 'PRIVATE KEY' (only for pem format)"),
+        Arg::new("bech32_hrp")
+            .long("bech32-hrp")
+            .value_name("HRP")
+            .default_value(DEFAULT_BECH32_HRP)
+            .help("Specifies the human-readable part of bech32 strings, e.g. 'cosmos' (only for bech32 format)"),
+        Arg::new("base64_alphabet")
+            .long("base64-alphabet")
+            .value_name("ALPHABET")
+            .help("Uses a custom 64-character alphabet, e.g. bcrypt's (only for base64 and base64nopad formats)"),
+        Arg::new("uppercase")
+            .short('U')
+            .long("uppercase")
+            .action(ArgAction::SetTrue)
+            .help("Uses uppercase output for case-insensitive formats (hex, base32, bech32) and UUIDs; an error for case-sensitive ones such as base64"),
+        Arg::new("hex_upper")
+            .long("hex-upper")
+            .action(ArgAction::SetTrue)
+            .help("Uses uppercase hex digits (only for hex format)"),
+        Arg::new("hex_prefix")
+            .long("hex-prefix")
+            .action(ArgAction::SetTrue)
+            .help("Prefixes hex output with 0x (only for hex format)"),
+        Arg::new("hex_separator")
+            .long("hex-separator")
+            .value_name("SEPARATOR")
+            .conflicts_with("hex_prefix")
+            .help("Inserts a separator between groups of hex bytes, e.g. ':' (only for hex format)"),
+        Arg::new("hex_group")
+            .long("hex-group")
+            .value_name("BYTES")
+            .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+            .default_value("1")
+            .help("Specifies the number of bytes per group when using --hex-separator"),
+        Arg::new("c_array")
+            .long("c-array")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["format", "raw"])
+            .help("Prints each key as a C array initializer such as {0xde, 0xad, ...}; shorthand for --lang c (only for key mode)"),
+        Arg::new("lang")
+            .long("lang")
+            .value_name("LANG")
+            .value_parser(["c", "python", "js"])
+            .conflicts_with_all(["format", "raw", "c_array"])
+            .help("Prints each key as an array literal for LANG: {0xde, ...} for c, bytes([0xde, ...]) for python or Uint8Array.from([0xde, ...]) for js; uppercase digits with --uppercase (only for key mode)"),
+        Arg::new("no_brackets")
+            .long("no-brackets")
+            .action(ArgAction::SetTrue)
+            .help("Prints only the comma-separated bytes of --c-array or --lang, without the surrounding array syntax"),
+        Arg::new("bytes_per_line")
+            .long("bytes-per-line")
+            .value_name("BYTES")
+            .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+            .help("Breaks the output of --c-array or --lang into lines of BYTES bytes each"),
+        Arg::new("prefix")
+            .long("prefix")
+            .value_name("PREFIX")
+            .conflicts_with("raw")
+            .help("Prepends a prefix to every generated key, e.g. 'sk_live_' (only for key mode)"),
+        Arg::new("suffix")
+            .long("suffix")
+            .value_name("SUFFIX")
+            .conflicts_with("raw")
+            .help("Appends a suffix to every generated key (only for key mode)"),
+        Arg::new("stdin")
+            .long("stdin")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["preset", "length", "bits", "count", "seed", "entropy_source"])
+            .help("Encodes raw bytes read from stdin instead of generating a new key (only for key mode)"),
+        Arg::new("fingerprint")
+            .long("fingerprint")
+            .action(ArgAction::SetTrue)
+            .help("Shows a fingerprint of each key: the first 8 bytes of its SHA-256 hash (only for key mode)"),
+        Arg::new("qr")
+            .long("qr")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("raw")
+            .help("Also renders each encoded key as a QR code on stderr, e.g. to scan it into an air-gapped device (only for key mode)"),
+        Arg::new("length")
+            .short('l')
+            .long("length")
+            .value_name("LENGTH")
+            .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+            .default_value("32")
+            .help("Specifies the key length in bytes (default: 32 bytes / 256 bits), or the number of characters in password mode. Ignored if preset is used."),
+        Arg::new("bits")
+            .long("bits")
+            .value_name("BITS")
+            .value_parser(value_parser!(u64).range(8..))
+            .conflicts_with("length")
+            .help("Specifies the key length in bits, e.g. 256; must be a multiple of 8 (only for key mode). Ignored if preset is used."),
+        Arg::new("charset")
+            .short('s')
+            .long("charset")
+            .value_name("CHARSET")
+            .value_parser(["alphanumeric", "alphabetic", "printable", "upperalnum", "unambiguous"])
+            .default_value("alphanumeric")
+            .help("Specifies the character set: alphanumeric, alphabetic, printable, upperalnum or unambiguous (only for password and license modes; license mode defaults to upperalnum)"),
+        Arg::new("groups")
+            .long("groups")
+            .value_name("GROUPS")
+            .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+            .default_value("4")
+            .help("Specifies the number of groups in a license key (only for license mode)"),
+        Arg::new("group_length")
+            .long("group-length")
+            .value_name("LENGTH")
+            .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+            .default_value("4")
+            .help("Specifies the number of characters per license key group (only for license mode)"),
+        Arg::new("exclude_ambiguous")
+            .long("exclude-ambiguous")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("charset")
+            .help("Leaves out easily confused characters such as 0/O and 1/I/L; same as '--charset unambiguous' (only for license mode)"),
+        Arg::new("require_upper")
+            .long("require-upper")
+            .value_name("COUNT")
+            .value_parser(value_parser!(usize))
+            .num_args(0..=1)
+            .default_missing_value("1")
+            .help("Requires at least COUNT (default 1) uppercase letters in the password (only for password mode)"),
+        Arg::new("require_lower")
+            .long("require-lower")
+            .value_name("COUNT")
+            .value_parser(value_parser!(usize))
+            .num_args(0..=1)
+            .default_missing_value("1")
+            .help("Requires at least COUNT (default 1) lowercase letters in the password (only for password mode)"),
+        Arg::new("require_digit")
+            .long("require-digit")
+            .value_name("COUNT")
+            .value_parser(value_parser!(usize))
+            .num_args(0..=1)
+            .default_missing_value("1")
+            .help("Requires at least COUNT (default 1) digits in the password (only for password mode)"),
+        Arg::new("require_symbol")
+            .long("require-symbol")
+            .value_name("COUNT")
+            .value_parser(value_parser!(usize))
+            .num_args(0..=1)
+            .default_missing_value("1")
+            .help("Requires at least COUNT (default 1) symbols in the password (only for password mode)"),
+        Arg::new("words")
+            .short('w')
+            .long("words")
+            .value_name("WORDS")
+            .value_parser(value_parser!(usize))
+            .help("Specifies the number of words (mnemonic mode: 12, 15, 18, 21 or 24, default: 24; passphrase mode default: 6)"),
+        Arg::new("separator")
+            .long("separator")
+            .value_name("SEPARATOR")
+            .help("Specifies the separator between passphrase words or license key groups (default: '-'), or between the values of a batch of keys or UUIDs (default: newline)"),
+        Arg::new("uuid_version")
+            .short('u')
+            .long("uuid-version")
+            .value_name("UUID_VERSION")
+            .value_parser(["v1", "v3", "v4", "v5", "v6", "v7", "v8", "nil", "max"])
+            .default_value("v4")
+            .help("Specifies the UUID version (only for UUID mode)"),
+        Arg::new("uuid_format")
+            .long("uuid-format")
+            .value_name("UUID_FORMAT")
+            .value_parser(["hyphenated", "simple", "urn", "braced", "short"])
+            .default_value("hyphenated")
+            .help("Specifies the UUID output format: hyphenated, simple, urn, braced or short (only for UUID and validate modes)"),
+        Arg::new("namespace")
+            .short('n')
+            .long("namespace")
+            .value_name("NAMESPACE")
+            .required_if_eq_any([("uuid_version", "v3"), ("uuid_version", "v5")])
+            .help("Specifies the UUID namespace as a UUID or one of dns, url, oid, x500 (only for UUID V3 or V5)"),
+        Arg::new("name")
+            .short('N')
+            .long("name")
+            .value_name("NAME")
+            .required_if_eq_any([("uuid_version", "v3"), ("uuid_version", "v5")])
+            .help("Specifies the name for UUID V3 or V5"),
+        Arg::new("dns")
+            .long("dns")
+            .value_name("HOST")
+            .conflicts_with_all(["uuid_version", "namespace", "name"])
+            .help("Generates a V5 UUID for the given hostname in the DNS namespace (implies UUID mode)"),
+        Arg::new("custom_bytes")
+            .short('b')
+            .long("custom-bytes")
+            .value_name("HEX")
+            .required_if_eq("uuid_version", "v8")
+            .help("Specifies the 16 custom bytes as a hex string (only for UUID V8)"),
+        Arg::new("value")
+            .long("value")
+            .value_name("VALUE")
+            .help("Specifies the UUID to check (for validate mode), or the key to re-encode (for convert mode; read from stdin if omitted)"),
+        Arg::new("compare")
+            .long("compare")
+            .value_name("OTHER")
+            .conflicts_with("timestamp")
+            .help("Checks whether --value and OTHER are the same UUID, ignoring case and notation; exits with status 1 if they differ (only for validate mode)"),
+        Arg::new("timestamp")
+            .long("timestamp")
+            .action(ArgAction::SetTrue)
+            .help("Prints the creation time embedded in a V1, V6 or V7 UUID as an RFC 3339 timestamp (only for validate mode)"),
+        Arg::new("entropy_source")
+            .long("entropy-source")
+            .value_name("SOURCE")
+            .default_value("os")
+            .conflicts_with("seed")
+            .help("Specifies where key bytes come from: 'os' for the operating system's CSPRNG, or the path of a device such as /dev/urandom (only for key mode)"),
+        Arg::new("raw")
+            .long("raw")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["format", "output", "clipboard", "export", "dotenv"])
+            .help("Writes the unencoded key bytes without a trailing newline, e.g. to pipe into another program (only for key mode)"),
+    ]
+}
+
+/// Returns the modes whose subcommand accepts the mode argument with the given id.
+fn arg_modes(id: &str) -> &'static [&'static str] {
+    match id {
+        "issuer" | "account" => &["totp"],
+        "from" | "to" => &["convert"],
+        "passphrase" | "salt" | "kdf" => &["derive"],
+        "preset" | "pem_label" | "bech32_hrp" | "base64_alphabet" | "hex_upper" | "hex_prefix" | "hex_separator"
+        | "hex_group" | "c_array" | "lang" | "no_brackets" | "bytes_per_line" | "prefix" | "suffix" | "stdin" | "fingerprint" | "qr" | "bits" | "entropy_source" | "raw" => {
+            &["key"]
+        }
+        "format" => &["key", "uuid", "derive"],
+        "uppercase" => &["key", "uuid"],
+        "length" => &["key", "password", "derive"],
+        "charset" => &["password", "license"],
+        "groups" | "group_length" | "exclude_ambiguous" => &["license"],
+        "require_upper" | "require_lower" | "require_digit" | "require_symbol" => &["password"],
+        "words" => &["mnemonic", "passphrase"],
+        "separator" => &["key", "uuid", "passphrase", "license"],
+        "uuid_version" | "namespace" | "name" | "dns" | "custom_bytes" => &["uuid"],
+        "uuid_format" => &["uuid", "validate"],
+        "value" => &["convert", "validate"],
+        "compare" | "timestamp" => &["validate"],
+        _ => &[],
+    }
+}
+
+/// Returns the mode in which the mode argument with the given id is required, if any.
+fn required_in(id: &str) -> Option<&'static str> {
+    match id {
+        "issuer" | "account" => Some("totp"),
+        "to" => Some("convert"),
+        "passphrase" | "salt" => Some("derive"),
+        "value" => Some("validate"),
+        _ => None,
+    }
+}
+
+/// Arguments that apply to every mode. They are global, so they are accepted both before and
+/// after the subcommand.
+fn global_args() -> Vec<Arg> {
+    vec![
+        Arg::new("count")
+            .short('c')
+            .long("count")
+            .value_name("COUNT")
+            .value_parser(value_parser!(u64).range(1..))
+            .default_value("1")
+            .help("Specifies how many keys or UUIDs to generate, printed one per line without a prefix when greater than 1"),
+        Arg::new("verbose")
+            .short('v')
+            .long("verbose")
+            .action(ArgAction::SetTrue)
+            .help("Prints the resolved configuration (mode, length, format, entropy, ...) to stderr before generating"),
+        Arg::new("show_entropy")
+            .long("show-entropy")
+            .action(ArgAction::SetTrue)
+            .help("Prints the entropy of the generated value in bits to stderr"),
+        Arg::new("output")
+            .short('o')
+            .long("output")
+            .value_name("OUTPUT")
+            .value_parser(["text", "json"])
+            .default_value("text")
+            .help("Specifies the output format: text or json (a JSON array when count is greater than 1)"),
+        Arg::new("output_file")
+            .long("output-file")
+            .value_name("PATH")
+            .value_parser(value_parser!(PathBuf))
+            .help("Writes the generated values to the given file (created with 0600 permissions on Unix) instead of stdout"),
+        Arg::new("export")
+            .long("export")
+            .value_name("NAME")
+            .value_parser(parse_env_name)
+            .conflicts_with("output")
+            .help("Prints the value as a shell assignment, export NAME='value', e.g. for eval \"$(genrs --export API_KEY)\"; several values are numbered NAME_1, NAME_2, ..."),
+        Arg::new("dotenv")
+            .long("dotenv")
+            .value_name("NAME")
+            .value_parser(parse_env_name)
+            .conflicts_with_all(["export", "output"])
+            .help("Prints the value as a .env line, NAME=value, quoted where needed; appends instead of overwriting with --output-file. Several values are numbered NAME_1, NAME_2, ..."),
+        Arg::new("check_entropy")
+            .long("check-entropy")
+            .action(ArgAction::SetTrue)
+            .help("Checks that the system's entropy source is usable and exits, with a non-zero status on failure"),
+        Arg::new("seed")
+            .long("seed")
+            .value_name("HEX")
+            .help("Seeds a ChaCha20 RNG with up to 32 bytes of hex for reproducible output. \
+                WARNING: seeded output is NOT cryptographically secure and is for testing only"),
+        Arg::new("quiet")
+            .short('q')
+            .long("quiet")
+            .action(ArgAction::SetTrue)
+            .help("Prints only the bare key or UUID, without any descriptive text"),
+        Arg::new("no_warn")
+            .long("no-warn")
+            .action(ArgAction::SetTrue)
+            .help("Suppresses the warning printed for keys, passwords and passphrases with less than 128 bits of entropy"),
+        Arg::new("clipboard")
+            .short('C')
+            .long("clipboard")
+            .action(ArgAction::SetTrue)
+            .conflicts_with("output_file")
+            .help("Copies the generated values to the clipboard instead of printing them"),
+    ]
+}
+
+fn main() {
+    // Without any arguments on a terminal, ask for the settings instead of silently using the
+    // defaults. Scripts and pipes keep the non-interactive behavior.
+    let interactive = std::env::args_os().len() == 1 && io::stdin().is_terminal() && io::stdout().is_terminal();
+    let root = match interactive {
+        true => match prompt_args() {
+            Ok(args) => cli().get_matches_from(args),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1);
+            }
+        },
+        false => cli().get_matches(),
+    };
+
+    if let Some(shell) = root.get_one::<Shell>("generate_completions") {
+        generate(*shell, &mut cli(), "genrs", &mut io::stdout());
+        return;
+    }
+
+    if root.get_flag("build_info") {
+        print_build_info(&root);
+        return;
+    }
+
+    if root.get_flag("list_presets") {
+        print_presets(root.get_one::<String>("output").unwrap());
+        return;
+    }
+
+    // Without a subcommand, the mode comes from the deprecated `--mode` and defaults to key mode.
+    let (mode, matches) = match root.subcommand() {
+        Some((mode, matches)) => {
+            // Mode arguments before the subcommand land on the hidden top-level copies, which
+            // the subcommand never reads, so reject them instead of silently ignoring them.
+            let misplaced = mode_args()
+                .into_iter()
+                .find(|arg| root.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine));
+            if let Some(arg) = misplaced {
+                let name = arg.get_long().unwrap_or(arg.get_id().as_str()).to_string();
+                let message = format!("'--{}' must be given after the '{}' subcommand", name, mode);
+                let mut command = cli().bin_name("genrs");
+                command.build();
+                let subcommand = command.find_subcommand_mut(mode).expect("every mode is a subcommand");
+                subcommand.error(ErrorKind::ArgumentConflict, message).exit();
+            }
+            (mode, matches)
+        }
+        None => {
+            let mode = match root.contains_id("dns") {
+                true => "uuid",
+                false => root.get_one::<String>("mode").unwrap().as_str(),
+            };
+            if root.value_source("mode") == Some(ValueSource::CommandLine) {
+                eprintln!(
+                    "Warning: --mode is deprecated and will be removed in the next release; use 'genrs {}' instead",
+                    mode
+                );
+            }
+            (mode, &root)
+        }
+    };
+    let count = *matches.get_one::<u64>("count").unwrap();
+    let output_file = matches.get_one::<PathBuf>("output_file");
+    let clipboard = matches.get_flag("clipboard");
+    // Only the key subcommand defines `--raw`; the others reject it while parsing.
+    let raw = matches.try_get_one::<bool>("raw").ok().flatten() == Some(&true);
+    let bare = matches.get_flag("quiet") || count > 1 || output_file.is_some() || clipboard || raw;
+    let show_entropy = matches.get_flag("show_entropy");
+    let no_warn = matches.get_flag("no_warn");
+    let verbose = matches.get_flag("verbose");
+    let output = matches.get_one::<String>("output").unwrap();
+
+    let mut seeded_rng = match matches.get_one::<String>("seed").map(String::as_str).map(parse_seed).transpose() {
+        Ok(rng) => rng,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+    if seeded_rng.is_some() {
+        eprintln!("Warning: seeded output is NOT cryptographically secure; use it for testing only");
+    }
+
+    // Fail fast with a diagnostic instead of panicking midway if the system's CSPRNG is unusable.
+    let check_entropy = matches.get_flag("check_entropy");
+    let uses_os_rng = match mode {
+        "validate" | "derive" | "convert" => false,
+        "key" => matches.get_one::<String>("entropy_source").unwrap().parse() == Ok(EntropySource::OsRng),
+        _ => true,
+    };
+    if (check_entropy || (uses_os_rng && seeded_rng.is_none())) && !entropy_available() {
+        eprintln!(
+            "Error: the system's entropy source is unavailable. \
+            Ensure that /dev/urandom (or the platform equivalent) is accessible, e.g. inside containers."
+        );
+        std::process::exit(1);
+    }
+    if check_entropy {
+        println!("Entropy source OK");
+        return;
+    }
 
-    let mode = matches.get_one::<String>("mode").unwrap();
+    let mut generated = Vec::new();
+    // Non-zero when the run succeeded but reports a negative result, e.g. `--compare` mismatches.
+    let mut exit_code = 0;
+    let mut raw_output = Vec::new();
 
     if mode == "key" {
-        if let Some(preset) = matches.get_one::<String>("preset") {
-            let (length, description) = match preset.as_str() {
-                "aes128" => (16, "AES-128"),
-                "aes192" => (24, "AES-192"),
-                "aes256" => (32, "AES-256"),
-                "hmac256" => (32, "HMAC-SHA256"),
-                "hmac512" => (64, "HMAC-SHA512"),
-                "jwt256" => (32, "JWT-256"),
-                "jwt512" => (64, "JWT-512"),
-                "apikey128" => (16, "API Key 128-bit"),
-                "apikey256" => (32, "API Key 256-bit"),
-                _ => unreachable!("Invalid preset"),
+        let encoding_formats: Vec<EncodingFormat> =
+            matches.get_many::<String>("format").unwrap().map(|format| format.parse().unwrap()).collect();
+        let uppercase = matches.get_flag("uppercase");
+        let hex_config = HexConfig {
+            uppercase: matches.get_flag("hex_upper") || uppercase,
+            prefix: matches.get_flag("hex_prefix"),
+        };
+        let array_language = match matches.get_flag("c_array") {
+            true => Some(ArrayLanguage::C),
+            false => matches.get_one::<String>("lang").map(|lang| lang.parse::<ArrayLanguage>().unwrap()),
+        };
+        let array_options = matches.get_flag("no_brackets") || matches.contains_id("bytes_per_line");
+        if array_options && array_language.is_none() {
+            eprintln!("Error: --no-brackets and --bytes-per-line require --c-array or --lang");
+            exit_with_failure(generated);
+        }
+        let array_config = ArrayConfig {
+            uppercase: hex_config.uppercase,
+            brackets: !matches.get_flag("no_brackets"),
+            bytes_per_line: matches.get_one::<usize>("bytes_per_line").copied().unwrap_or(0),
+        };
+        let hex_separator = matches.get_one::<String>("hex_separator");
+        let hex_group = *matches.get_one::<usize>("hex_group").unwrap();
+        let pem_label = matches.get_one::<String>("pem_label").unwrap();
+        let bech32_hrp = matches.get_one::<String>("bech32_hrp").unwrap();
+        // (padded, unpadded) codecs for `base64` and `base64nopad`, built once for all keys.
+        let custom_base64 = match matches.get_one::<String>("base64_alphabet") {
+            Some(alphabet) => match (CustomBase64::new(alphabet, true), CustomBase64::new(alphabet, false)) {
+                (Ok(padded), Ok(unpadded)) => Some((padded, unpadded)),
+                (Err(err), _) | (_, Err(err)) => {
+                    eprintln!("Error: {}", err);
+                    exit_with_failure(generated);
+                }
+            },
+            None => None,
+        };
+        let entropy_source: EntropySource = match matches.get_one::<String>("entropy_source").unwrap().parse() {
+            Ok(entropy_source) => entropy_source,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                exit_with_failure(generated);
+            }
+        };
+        let prefix = matches.get_one::<String>("prefix").map(String::as_str);
+        let suffix = matches.get_one::<String>("suffix").map(String::as_str);
+
+        let stdin_key = match matches.get_flag("stdin") {
+            true => match read_stdin_bytes() {
+                Ok(key) if key.is_empty() => {
+                    eprintln!("Error: --stdin was given, but stdin is empty");
+                    exit_with_failure(generated);
+                }
+                Ok(key) => Some(key),
+                Err(err) => {
+                    eprintln!("Error: failed to read from stdin: {}", err);
+                    exit_with_failure(generated);
+                }
+            },
+            false => None,
+        };
+
+        let preset: Option<KeyPreset> = matches.get_one::<String>("preset").map(|preset| preset.parse().unwrap());
+        let length = if let Some(key) = &stdin_key {
+            key.len()
+        } else if let Some(preset) = preset {
+            preset.byte_length()
+        } else {
+            match matches.get_one::<u64>("bits") {
+                Some(bits) if bits % 8 != 0 => {
+                    eprintln!("Error: --bits must be a multiple of 8, got {}", bits);
+                    exit_with_failure(generated);
+                }
+                Some(bits) => (bits / 8) as usize,
+                None => *matches.get_one::<usize>("length").unwrap(),
+            }
+        };
+        if length > MAX_KEY_LENGTH {
+            eprintln!("Error: a key must be at most {} bytes long, got {}", MAX_KEY_LENGTH, length);
+            exit_with_failure(generated);
+        }
+        // Short keys are fine for presets such as nonces, but usually a mistake for secret keys.
+        if stdin_key.is_none() && preset.is_none() {
+            warn_if_weak(entropy_bits(length, EncodingFormat::Hex), no_warn);
+        }
+        let format_name = |format: EncodingFormat| match array_language {
+            Some(language) => format!("{}-array", language),
+            None => format.to_string(),
+        };
+        let describe = |format: EncodingFormat| match (&stdin_key, preset) {
+            (Some(_), _) => format!("Encoded Key ({} format, {} bytes from stdin)", format_name(format), length),
+            (None, Some(preset)) if encoding_formats.len() > 1 => format!(
+                "Generated Key ({} preset, {} format, {} bytes)",
+                preset.description(),
+                format_name(format),
+                length
+            ),
+            (None, Some(preset)) => format!("Generated Key ({} preset, {} bytes)", preset.description(), length),
+            (None, None) => format!("Generated Key ({} format, {} bytes)", format_name(format), length),
+        };
+
+        if show_entropy && stdin_key.is_none() {
+            eprintln!("Entropy: {:.1} bits", entropy_bits(length, encoding_formats[0]));
+        }
+        if verbose {
+            let source = match (&stdin_key, &seeded_rng) {
+                (Some(_), _) => "stdin".to_string(),
+                (None, Some(_)) => "seeded ChaCha20".to_string(),
+                (None, None) => entropy_source.to_string(),
             };
+            let formats: Vec<String> = encoding_formats.iter().map(|&format| format_name(format)).collect();
+            print_config(&[
+                ("mode", "key".to_string()),
+                ("preset", preset.map_or("none".to_string(), |preset| preset.to_string())),
+                ("length", format!("{} bytes", length)),
+                ("format", formats.join(",")),
+                ("source", source),
+                ("entropy", format!("{:.1} bits", entropy_bits(length, EncodingFormat::Hex))),
+                ("count", count.to_string()),
+            ]);
+        }
 
-            let format = matches.get_one::<String>("format").unwrap();
-            let encoding_format = match format.as_str() {
-                "hex" => EncodingFormat::Hex,
-                "base64" => EncodingFormat::Base64,
-                _ => unreachable!("Invalid format"),
+        for _ in 0..count {
+            let key = match (&stdin_key, seeded_rng.as_mut()) {
+                (Some(key), _) => key.clone(),
+                (None, Some(rng)) => generate_key_with_rng(length, rng),
+                (None, None) => match generate_key_from_source(length, &entropy_source) {
+                    Ok(key) => key,
+                    Err(err) => {
+                        eprintln!("Error: {}", err);
+                        wipe_bytes(raw_output);
+                        exit_with_failure(generated);
+                    }
+                },
             };
+            if raw {
+                if matches.get_flag("fingerprint") {
+                    eprintln!("Fingerprint (SHA-256, first 8 bytes): {}", key_fingerprint(&key));
+                }
+                raw_output.extend_from_slice(&key);
+                wipe_bytes(key);
+                continue;
+            }
+
+            let fingerprint = matches.get_flag("fingerprint").then(|| key_fingerprint(&key));
+            if let Some(fingerprint) = &fingerprint {
+                if bare && output != "json" {
+                    eprintln!("Fingerprint (SHA-256, first 8 bytes): {}", fingerprint);
+                }
+            }
+
+            // Every requested format describes the same key bytes.
+            for &encoding_format in &encoding_formats {
+                let encoded = match encoding_format {
+                    _ if array_language.is_some() => {
+                        Ok(encode_array_literal(&key, array_language.unwrap(), array_config))
+                    }
+                    EncodingFormat::Hex => Ok(match hex_separator {
+                        Some(separator) if hex_config.uppercase => format_hex(&key, separator, hex_group).to_uppercase(),
+                        Some(separator) => format_hex(&key, separator, hex_group),
+                        None => encode_hex_ct(&key, hex_config),
+                    }),
+                    EncodingFormat::Bech32 => encode_bech32(&key, bech32_hrp).map(|mut encoded| {
+                        if uppercase {
+                            encoded.make_ascii_uppercase();
+                        }
+                        encoded
+                    }),
+                    _ if uppercase => encode_key_uppercase(&key, encoding_format),
+                    EncodingFormat::Base64 | EncodingFormat::Base64NoPad if custom_base64.is_some() => {
+                        let (padded, unpadded) = custom_base64.as_ref().unwrap();
+                        Ok(match encoding_format.is_padded() {
+                            true => padded.encode(&key),
+                            false => unpadded.encode(&key),
+                        })
+                    }
+                    EncodingFormat::Pem => Ok(encode_pem(&key, pem_label)),
+                    _ => encode_key(&key, encoding_format),
+                };
+                let encoded_key = match encoded {
+                    Ok(encoded_key) if prefix.is_some() || suffix.is_some() => decorate(&encoded_key, prefix, suffix),
+                    Ok(encoded_key) => encoded_key,
+                    Err(err) => {
+                        eprintln!("Error: {}", err);
+                        wipe_bytes(key);
+                        exit_with_failure(generated);
+                    }
+                };
+
+                if matches.get_flag("qr") {
+                    if let Err(err) = print_qr(&encoded_key) {
+                        eprintln!("Error: cannot render the key as a QR code: {}", err);
+                        wipe_bytes(key);
+                        wipe_string(encoded_key);
+                        exit_with_failure(generated);
+                    }
+                }
 
-            let key = generate_key(length);
-            match encode_key(key, encoding_format) {
-                Ok(encoded_key) => {
-                    println!("Generated Key ({} preset, {} bytes): {}", description, length, encoded_key);
+                let mut description = describe(encoding_format);
+                let mut json = json!({
+                    "mode": "key",
+                    "preset": preset.map(|preset| preset.to_string()),
+                    "format": format_name(encoding_format),
+                    "length": length,
+                    "value": encoded_key,
+                });
+                if let Some(fingerprint) = &fingerprint {
+                    description = format!("{} [fingerprint SHA-256/64: {}]", description, fingerprint);
+                    json["fingerprint"] = json!(fingerprint);
                 }
+
+                generated.push(Generated {
+                    description,
+                    json,
+                    value: encoded_key,
+                });
+            }
+            wipe_bytes(key);
+        }
+    } else if mode == "uuid" {
+        // `--dns <HOST>` is a shortcut for `--uuid-version v5 --namespace dns --name <HOST>`.
+        let dns = matches.get_one::<String>("dns").map(String::as_str);
+        let uuid_version = match dns {
+            Some(_) => "v5",
+            None => matches.get_one::<String>("uuid_version").unwrap().as_str(),
+        };
+        let namespace = match dns {
+            Some(_) => Some("dns"),
+            None => matches.get_one::<String>("namespace").map(String::as_str),
+        };
+        let name = dns.or(matches.get_one::<String>("name").map(String::as_str));
+
+        let uuid_version_enum = if uuid_version == "v8" {
+            let custom_bytes = matches.get_one::<String>("custom_bytes").unwrap();
+            match decode_key(custom_bytes, EncodingFormat::Hex).and_then(|bytes| UuidVersion::v8_from_slice(&bytes)) {
+                Ok(version) => version,
                 Err(err) => {
-                    eprintln!("Error: {}", err);
+                    eprintln!("Error generating UUID: {}", err);
+                    exit_with_failure(generated);
                 }
             }
         } else {
-            let format = matches.get_one::<String>("format").unwrap();
-            let length: usize = *matches.get_one::<usize>("length").unwrap();
-            let encoding_format = match format.as_str() {
-                "hex" => EncodingFormat::Hex,
-                "base64" => EncodingFormat::Base64,
-                _ => unreachable!("Invalid format"),
+            uuid_version.parse::<UuidVersion>().unwrap()
+        };
+        if seeded_rng.is_some() && uuid_version_enum != UuidVersion::V4 {
+            eprintln!("Error: --seed is only supported for v4 UUIDs");
+            exit_with_failure(generated);
+        }
+        if !uuid_version_enum.is_name_based() && (namespace.is_some() || name.is_some()) {
+            eprintln!(
+                "Warning: --namespace and --name are ignored for UUID {}; only v3 and v5 UUIDs are derived from them",
+                uuid_version_enum
+            );
+        }
+
+        let uuid_format: UuidFormat = matches.get_one::<String>("uuid_format").unwrap().parse().unwrap();
+        // `--format` only applies to UUIDs when given explicitly, so the default output stays the
+        // canonical UUID string.
+        let byte_formats: Option<Vec<EncodingFormat>> = match matches.value_source("format") {
+            Some(ValueSource::CommandLine) => {
+                Some(matches.get_many::<String>("format").unwrap().map(|format| format.parse().unwrap()).collect())
+            }
+            _ => None,
+        };
+        if byte_formats.is_some() && matches.value_source("uuid_format") == Some(ValueSource::CommandLine) {
+            eprintln!("Error: --format and --uuid-format cannot be combined");
+            exit_with_failure(generated);
+        }
+        if verbose {
+            let format = match &byte_formats {
+                Some(byte_formats) => {
+                    let formats: Vec<String> = byte_formats.iter().map(EncodingFormat::to_string).collect();
+                    format!("{} (16 bytes)", formats.join(","))
+                }
+                None => uuid_format.to_string(),
             };
+            print_config(&[
+                ("mode", "uuid".to_string()),
+                ("uuid version", uuid_version_enum.to_string()),
+                ("format", format),
+                ("namespace", namespace.unwrap_or("none").to_string()),
+                ("name", name.unwrap_or("none").to_string()),
+                ("count", count.to_string()),
+            ]);
+        }
+        let namespace_uuid = match namespace.map(parse_namespace).transpose() {
+            Ok(namespace_uuid) => namespace_uuid,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                exit_with_failure(generated);
+            }
+        };
+
+        let uppercase = matches.get_flag("uppercase");
+        let encode_bytes = |uuid: &Uuid, byte_format: EncodingFormat| match uppercase {
+            true => encode_key_uppercase(uuid.as_bytes(), byte_format),
+            false => encode_key(uuid.as_bytes(), byte_format),
+        };
+        let format_text = |uuid: &Uuid| match uppercase {
+            true => format_uuid_uppercase(uuid, uuid_format),
+            false => Ok(format_uuid(uuid, uuid_format)),
+        };
 
-            let key = generate_key(length);
-            match encode_key(key, encoding_format) {
-                Ok(encoded_key) => {
-                    println!(
-                        "Generated Key ({} format, {} bytes): {}",
-                        format, length, encoded_key
-                    );
+        // Seeded V4 UUIDs are reproducible test fixtures; everything else comes from the OS CSPRNG.
+        let uuids = match seeded_rng.as_mut() {
+            Some(rng) => Ok((0..count).map(|_| deterministic_uuid_v4(rng)).collect()),
+            None => generate_uuids(uuid_version_enum, count as usize, namespace_uuid, name),
+        };
+        match uuids {
+            Ok(uuids) => {
+                for uuid in uuids {
+                    if let Some(byte_formats) = &byte_formats {
+                        for &byte_format in byte_formats {
+                            let encoded = match encode_bytes(&uuid, byte_format) {
+                                Ok(encoded) => encoded,
+                                Err(err) => {
+                                    eprintln!("Error: {}", err);
+                                    exit_with_failure(generated);
+                                }
+                            };
+                            generated.push(Generated {
+                                description: format!(
+                                    "Generated UUID (version {}, {} format, 16 bytes)",
+                                    uuid_version_enum, byte_format
+                                ),
+                                json: json!({
+                                    "mode": "uuid",
+                                    "version": uuid_version_enum.to_string(),
+                                    "format": byte_format.to_string(),
+                                    "value": encoded,
+                                }),
+                                value: encoded,
+                            });
+                        }
+                        continue;
+                    }
+
+                    let uuid = match format_text(&uuid) {
+                        Ok(uuid) => uuid,
+                        Err(err) => {
+                            eprintln!("Error: {}", err);
+                            exit_with_failure(generated);
+                        }
+                    };
+                    generated.push(Generated {
+                        description: format!("Generated UUID (version {})", uuid_version_enum),
+                        json: json!({
+                            "mode": "uuid",
+                            "version": uuid_version_enum.to_string(),
+                            "value": uuid,
+                        }),
+                        value: uuid,
+                    });
                 }
+            }
+            Err(err) => {
+                eprintln!("Error generating UUID: {}", err);
+                exit_with_failure(generated);
+            }
+        }
+    } else if mode == "password" {
+        let length: usize = *matches.get_one::<usize>("length").unwrap();
+        let charset: Charset = matches.get_one::<String>("charset").unwrap().parse().unwrap();
+        let required = |id: &str| matches.get_one::<usize>(id).copied().unwrap_or(0);
+        let policy = PasswordPolicy {
+            charset,
+            min_uppercase: required("require_upper"),
+            min_lowercase: required("require_lower"),
+            min_digits: required("require_digit"),
+            min_symbols: required("require_symbol"),
+        };
+        if policy.required_length() > length {
+            eprintln!(
+                "Error: the password policy requires at least {} characters, but --length is {}",
+                policy.required_length(),
+                length
+            );
+            exit_with_failure(generated);
+        }
+
+        if show_entropy {
+            eprintln!("Entropy: {:.1} bits", policy.entropy_bits(length));
+        }
+        if verbose {
+            print_config(&[
+                ("mode", "password".to_string()),
+                ("length", format!("{} characters", length)),
+                ("charset", charset.to_string()),
+                (
+                    "required",
+                    format!(
+                        "{} upper, {} lower, {} digits, {} symbols",
+                        policy.min_uppercase, policy.min_lowercase, policy.min_digits, policy.min_symbols
+                    ),
+                ),
+                ("entropy", format!("{:.1} bits", policy.entropy_bits(length))),
+                ("count", count.to_string()),
+            ]);
+        }
+        warn_if_weak(policy.entropy_bits(length), no_warn);
+
+        for _ in 0..count {
+            let password = match seeded_rng.as_mut() {
+                Some(rng) if policy.required_length() > 0 => generate_policy_password_with_rng(length, policy, rng),
+                Some(rng) => generate_password_with_rng(length, charset, rng),
+                None if policy.required_length() > 0 => generate_policy_password(length, policy),
+                None => generate_password(length, charset),
+            };
+            generated.push(Generated {
+                description: format!("Generated Password ({} charset, {} characters)", charset, length),
+                json: json!({
+                    "mode": "password",
+                    "charset": charset.to_string(),
+                    "length": length,
+                    "value": password,
+                }),
+                value: password,
+            });
+        }
+    } else if mode == "mnemonic" {
+        let words = matches.get_one::<usize>("words").copied().unwrap_or(24);
+        let mnemonic_length = match MnemonicLength::from_word_count(words) {
+            Ok(mnemonic_length) => mnemonic_length,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                exit_with_failure(generated);
+            }
+        };
+
+        if show_entropy {
+            eprintln!("Entropy: {:.1} bits", (mnemonic_length.entropy_bytes() * 8) as f64);
+        }
+        if verbose {
+            print_config(&[
+                ("mode", "mnemonic".to_string()),
+                ("words", words.to_string()),
+                ("entropy", format!("{:.1} bits", (mnemonic_length.entropy_bytes() * 8) as f64)),
+                ("count", count.to_string()),
+            ]);
+        }
+
+        for _ in 0..count {
+            let mnemonic = match seeded_rng.as_mut() {
+                Some(rng) => generate_mnemonic_with_rng(mnemonic_length, rng),
+                None => generate_mnemonic(mnemonic_length),
+            };
+            generated.push(Generated {
+                description: format!("Generated Mnemonic ({} words)", words),
+                json: json!({
+                    "mode": "mnemonic",
+                    "words": words,
+                    "value": mnemonic,
+                }),
+                value: mnemonic,
+            });
+        }
+    } else if mode == "passphrase" {
+        let words = matches.get_one::<usize>("words").copied().unwrap_or(6);
+        let separator = matches.get_one::<String>("separator").map_or("-", String::as_str);
+        let bits = passphrase_entropy_bits(words);
+
+        if show_entropy {
+            eprintln!("Entropy: {:.1} bits", bits);
+        }
+        if verbose {
+            print_config(&[
+                ("mode", "passphrase".to_string()),
+                ("words", words.to_string()),
+                ("separator", format!("'{}'", separator)),
+                ("entropy", format!("{:.1} bits", bits)),
+                ("count", count.to_string()),
+            ]);
+        }
+        warn_if_weak(bits, no_warn);
+
+        for _ in 0..count {
+            let passphrase = match seeded_rng.as_mut() {
+                Some(rng) => generate_passphrase_with_rng(words, separator, rng),
+                None => generate_passphrase(words, separator),
+            };
+            generated.push(Generated {
+                description: format!("Generated Passphrase ({} words, {:.1} bits of entropy)", words, bits),
+                json: json!({
+                    "mode": "passphrase",
+                    "words": words,
+                    "entropy_bits": bits,
+                    "value": passphrase,
+                }),
+                value: passphrase,
+            });
+        }
+    } else if mode == "license" {
+        let groups = *matches.get_one::<usize>("groups").unwrap();
+        let group_len = *matches.get_one::<usize>("group_length").unwrap();
+        let charset: Charset = match (matches.get_flag("exclude_ambiguous"), matches.value_source("charset")) {
+            (true, _) => Charset::Unambiguous,
+            (false, Some(ValueSource::CommandLine)) => matches.get_one::<String>("charset").unwrap().parse().unwrap(),
+            (false, _) => Charset::UppercaseAlphanumeric,
+        };
+        let separator = matches.get_one::<String>("separator").map_or("-", String::as_str);
+        let mut separator_chars = separator.chars();
+        let separator = match (separator_chars.next(), separator_chars.next()) {
+            (Some(separator), None) => separator,
+            _ => {
+                eprintln!("Error: the license key separator must be a single character, got '{}'", separator);
+                exit_with_failure(generated);
+            }
+        };
+        let bits = entropy_bits(groups * group_len, charset);
+
+        if show_entropy {
+            eprintln!("Entropy: {:.1} bits", bits);
+        }
+        if verbose {
+            print_config(&[
+                ("mode", "license".to_string()),
+                ("groups", groups.to_string()),
+                ("group length", group_len.to_string()),
+                ("charset", charset.to_string()),
+                ("separator", format!("'{}'", separator)),
+                ("entropy", format!("{:.1} bits", bits)),
+                ("count", count.to_string()),
+            ]);
+        }
+
+        for _ in 0..count {
+            let license_key = match seeded_rng.as_mut() {
+                Some(rng) => generate_license_key_with_rng(groups, group_len, charset, separator, rng),
+                None => generate_license_key(groups, group_len, charset, separator),
+            };
+            generated.push(Generated {
+                description: format!(
+                    "Generated License Key ({}x{}, {} charset, {:.1} bits of entropy)",
+                    groups, group_len, charset, bits
+                ),
+                json: json!({
+                    "mode": "license",
+                    "groups": groups,
+                    "group_length": group_len,
+                    "charset": charset.to_string(),
+                    "entropy_bits": bits,
+                    "value": license_key,
+                }),
+                value: license_key,
+            });
+        }
+    } else if mode == "derive" {
+        #[cfg(feature = "kdf")]
+        {
+            let passphrase = matches.get_one::<String>("passphrase").unwrap();
+            let salt = matches.get_one::<String>("salt").unwrap();
+            let kdf: Kdf = matches.get_one::<String>("kdf").unwrap().parse().unwrap();
+            let length: usize = *matches.get_one::<usize>("length").unwrap();
+            let encoding_formats: Vec<EncodingFormat> =
+                matches.get_many::<String>("format").unwrap().map(|format| format.parse().unwrap()).collect();
+            if verbose {
+                let formats: Vec<String> = encoding_formats.iter().map(EncodingFormat::to_string).collect();
+                print_config(&[
+                    ("mode", "derive".to_string()),
+                    ("kdf", kdf.to_string()),
+                    ("length", format!("{} bytes", length)),
+                    ("format", formats.join(",")),
+                ]);
+            }
+
+            let key = match derive_key(passphrase, salt.as_bytes(), length, kdf) {
+                Ok(key) => key,
                 Err(err) => {
                     eprintln!("Error: {}", err);
+                    exit_with_failure(generated);
+                }
+            };
+            // Every requested format describes the same derived key.
+            for &encoding_format in &encoding_formats {
+                match encode_key(&key, encoding_format) {
+                    Ok(encoded_key) => generated.push(Generated {
+                        description: format!("Derived Key ({}, {} format, {} bytes)", kdf, encoding_format, length),
+                        json: json!({
+                            "mode": "derive",
+                            "kdf": kdf.to_string(),
+                            "format": encoding_format.to_string(),
+                            "length": length,
+                            "value": encoded_key,
+                        }),
+                        value: encoded_key,
+                    }),
+                    Err(err) => {
+                        eprintln!("Error: {}", err);
+                        wipe_bytes(key);
+                        exit_with_failure(generated);
+                    }
                 }
             }
+            wipe_bytes(key);
         }
-    } else if mode == "uuid" {
-        let uuid_version = matches.get_one::<String>("uuid_version").unwrap();
-        let namespace = matches.get_one::<String>("namespace");
-        let name = matches.get_one::<String>("name");
+        #[cfg(not(feature = "kdf"))]
+        {
+            eprintln!("Error: derive mode requires genrs to be built with the `kdf` feature");
+            exit_with_failure(generated);
+        }
+    } else if mode == "convert" {
+        let to: EncodingFormat = matches.get_one::<String>("to").unwrap().parse().unwrap();
+        let value = match matches.get_one::<String>("value") {
+            Some(value) => value.clone(),
+            None => {
+                let mut input = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut input) {
+                    eprintln!("Error: failed to read from stdin: {}", err);
+                    exit_with_failure(generated);
+                }
+                input
+            }
+        };
+        let from: EncodingFormat = match matches.get_one::<String>("from") {
+            Some(from) => from.parse().unwrap(),
+            None => match detect_encoding(&value) {
+                Some(from) => from,
+                None => {
+                    eprintln!("Error: could not detect the encoding of the input; specify it with --from");
+                    exit_with_failure(generated);
+                }
+            },
+        };
+        if verbose {
+            print_config(&[("mode", "convert".to_string()), ("from", from.to_string()), ("to", to.to_string())]);
+        }
+
+        // PEM blocks span several lines; every other format is a single trimmed token.
+        let input = if from == EncodingFormat::Pem { value.as_str() } else { value.trim() };
+        let converted = decode_key(input, from).and_then(|key| encode_key(&key, to).map(|encoded| (key.len(), encoded)));
+        match converted {
+            Ok((length, encoded_key)) => generated.push(Generated {
+                description: format!("Converted Key ({} to {}, {} bytes)", from, to, length),
+                json: json!({
+                    "mode": "convert",
+                    "from": from.to_string(),
+                    "format": to.to_string(),
+                    "length": length,
+                    "value": encoded_key,
+                }),
+                value: encoded_key,
+            }),
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                exit_with_failure(generated);
+            }
+        }
+    } else if mode == "totp" {
+        let issuer = matches.get_one::<String>("issuer").unwrap();
+        let account = matches.get_one::<String>("account").unwrap();
 
-        let uuid_version_enum = match uuid_version.as_str() {
-            "v1" => UuidVersion::V1,
-            "v3" => UuidVersion::V3,
-            "v4" => UuidVersion::V4,
-            "v5" => UuidVersion::V5,
-            _ => unreachable!("Invalid UUID version"),
+        if show_entropy {
+            eprintln!("Entropy: {:.1} bits", (TOTP_SECRET_LENGTH * 8) as f64);
+        }
+        if verbose {
+            print_config(&[
+                ("mode", "totp".to_string()),
+                ("secret length", format!("{} bytes", TOTP_SECRET_LENGTH)),
+                ("entropy", format!("{:.1} bits", (TOTP_SECRET_LENGTH * 8) as f64)),
+                ("count", count.to_string()),
+            ]);
+        }
+
+        for _ in 0..count {
+            let secret = match seeded_rng.as_mut() {
+                Some(rng) => generate_key_with_rng(TOTP_SECRET_LENGTH, rng),
+                None => generate_key(TOTP_SECRET_LENGTH),
+            };
+            let uri = totp_uri(&secret, issuer, account);
+            generated.push(Generated {
+                description: format!("TOTP Provisioning URI ({} bytes secret)", TOTP_SECRET_LENGTH),
+                json: json!({
+                    "mode": "totp",
+                    "issuer": issuer,
+                    "account": account,
+                    "secret": encode_key(&secret, EncodingFormat::Base32).unwrap(),
+                    "value": uri,
+                }),
+                value: uri,
+            });
+        }
+    } else if mode == "validate" {
+        let value = matches.get_one::<String>("value").unwrap();
+        if verbose {
+            print_config(&[("mode", "validate".to_string()), ("value", value.clone())]);
+        }
+
+        // `Uuid::parse_str` accepts the hyphenated, simple, braced and URN notations in any case.
+        let parse = |value: &str| match Uuid::parse_str(value) {
+            Ok(uuid) => uuid,
+            Err(err) => {
+                eprintln!("Error: invalid UUID '{}': {}", value, err);
+                std::process::exit(1);
+            }
+        };
+        let uuid = parse(value);
+        let uuid_format: UuidFormat = matches.get_one::<String>("uuid_format").unwrap().parse().unwrap();
+        let canonical = format_uuid(&uuid, uuid_format);
+        let timestamp = uuid_timestamp(&uuid);
+        let mut json = json!({
+            "mode": "validate",
+            "version": uuid.get_version_num(),
+            "variant": format!("{:?}", uuid.get_variant()),
+            "value": canonical,
+        });
+        if let Some(timestamp) = &timestamp {
+            json["timestamp"] = json!(timestamp);
+        }
+
+        if let Some(other) = matches.get_one::<String>("compare") {
+            let other = parse(other);
+            let equal = uuid == other;
+            json["compare"] = json!(format_uuid(&other, uuid_format));
+            json["equal"] = json!(equal);
+            generated.push(Generated {
+                description: format!("UUID Comparison ({} vs {})", canonical, format_uuid(&other, uuid_format)),
+                json,
+                value: if equal { "equal" } else { "different" }.to_string(),
+            });
+            if !equal {
+                exit_code = 1;
+            }
+        } else if matches.get_flag("timestamp") {
+            let timestamp = match timestamp {
+                Some(timestamp) => timestamp,
+                None => {
+                    eprintln!("Error: UUID version {} has no timestamp", uuid.get_version_num());
+                    exit_with_failure(generated);
+                }
+            };
+            generated.push(Generated {
+                description: format!("UUID Timestamp (version {})", uuid.get_version_num()),
+                json,
+                value: timestamp,
+            });
+        } else {
+            generated.push(Generated {
+                description: format!(
+                    "Valid UUID (version {}, variant {:?})",
+                    uuid.get_version_num(),
+                    uuid.get_variant()
+                ),
+                json,
+                value: canonical,
+            });
+        }
+    }
+
+    if raw {
+        if mode != "key" {
+            eprintln!("Error: --raw is only supported in key mode");
+            exit_with_failure(generated);
+        }
+        let written = match output_file {
+            Some(path) => write_output_file(path, &raw_output, false),
+            None => write_stdout(&raw_output),
         };
+        wipe_bytes(raw_output);
+        check_written(written, "raw output");
+        return;
+    }
 
-        let namespace_uuid = namespace.map(|ns| Uuid::parse_str(ns).expect("Invalid UUID format for namespace"));
-        let uuid_result = generate_uuid(uuid_version_enum, namespace_uuid, name.map(String::as_str));
+    // `--export` and `--dotenv` print `NAME=value` assignments, with their own prefix and quoting.
+    let assignment = match (matches.get_one::<String>("export"), matches.get_one::<String>("dotenv")) {
+        (Some(name), _) => Some((name, "export ", shell_quote as fn(&str) -> String)),
+        (None, Some(name)) => Some((name, "", dotenv_quote as fn(&str) -> String)),
+        (None, None) => None,
+    };
 
-        match uuid_result {
-            Ok(uuid) => {
-                println!("Generated UUID (version {}): {}", uuid_version, uuid);
+    // Render into a byte buffer that is wiped once written, instead of leaving copies of the
+    // secrets behind in temporary strings.
+    let mut rendered = Vec::new();
+    // Every requested format of every key is a separate value, so this can exceed `count`.
+    let value_count = generated.len();
+    if output == "json" {
+        let values: Vec<Value> = generated
+            .into_iter()
+            .map(|generated| {
+                wipe_string(generated.value);
+                generated.json
+            })
+            .collect();
+        let values = if value_count > 1 { vec![Value::Array(values)] } else { values };
+        for value in values {
+            serde_json::to_writer(&mut rendered, &value).expect("serializing JSON into a Vec cannot fail");
+            rendered.push(b'\n');
+            wipe_json(value);
+        }
+    } else if let Some((name, prefix, quote)) = assignment {
+        let numbered = generated.len() > 1;
+        for (index, generated) in generated.into_iter().enumerate() {
+            let name = match numbered {
+                true => format!("{}_{}", name, index + 1),
+                false => name.clone(),
+            };
+            let line = format!("{}{}={}\n", prefix, name, quote(&generated.value));
+            rendered.extend_from_slice(line.as_bytes());
+            wipe_string(line);
+            wipe_string(generated.value);
+            wipe_json(generated.json);
+        }
+    } else {
+        // In key and UUID mode, `--separator` joins a batch on one line, e.g. for a SQL `IN (...)` list.
+        let separator = match mode {
+            "key" | "uuid" => matches.get_one::<String>("separator").map_or("\n", String::as_str),
+            _ => "\n",
+        };
+        let has_values = !generated.is_empty();
+        for (index, generated) in generated.into_iter().enumerate() {
+            if index > 0 {
+                rendered.extend_from_slice(separator.as_bytes());
             }
-            Err(err) => {
-                eprintln!("Error generating UUID: {}", err);
+            if !bare {
+                rendered.extend_from_slice(generated.description.as_bytes());
+                rendered.extend_from_slice(b": ");
             }
+            rendered.extend_from_slice(generated.value.as_bytes());
+            wipe_string(generated.value);
+            wipe_json(generated.json);
+        }
+        if has_values {
+            rendered.push(b'\n');
         }
     }
+
+    if clipboard {
+        let text = std::str::from_utf8(&rendered).expect("rendered output is UTF-8");
+        let copied = copy_to_clipboard(text.trim_end_matches('\n'));
+        wipe_bytes(rendered);
+        if let Err(err) = copied {
+            eprintln!("Error: failed to copy to clipboard: {}", err);
+            std::process::exit(1);
+        }
+        println!("Copied {} value(s) to the clipboard", value_count);
+    } else {
+        let written = match output_file {
+            Some(path) => write_output_file(path, &rendered, matches.contains_id("dotenv")),
+            None => write_stdout(&rendered),
+        };
+        wipe_bytes(rendered);
+        match output_file {
+            Some(path) => check_written(written, &format!("'{}'", path.display())),
+            None => check_written(written, "output"),
+        }
+    }
+
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+}
+
+/// Renders `value` as a QR code on stderr, so that it can be scanned while stdout still carries
+/// only the key itself.
+fn print_qr(value: &str) -> Result<(), QrError> {
+    let image = QrCode::new(value.as_bytes())?
+        .render::<Dense1x2>()
+        // Light-on-dark, so that the code scans on the usual dark terminal background.
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build();
+    eprintln!("{}", image);
+    wipe_string(image);
+    Ok(())
+}
+
+/// Asks for the mode and its most common settings on the terminal and returns them as
+/// command-line arguments, so the answers go through the same parsing and validation as flags.
+fn prompt_args() -> dialoguer::Result<Vec<String>> {
+    let theme = ColorfulTheme::default();
+    let mut args = vec!["genrs".to_string()];
+
+    let modes = ["key", "uuid"];
+    let mode = Select::with_theme(&theme)
+        .with_prompt("What do you want to generate?")
+        .items(&modes)
+        .default(0)
+        .interact()?;
+    args.push(modes[mode].to_string());
+
+    match modes[mode] {
+        "key" => {
+            let length: usize = Input::with_theme(&theme)
+                .with_prompt("Key length in bytes")
+                .default(32)
+                .validate_with(|length: &usize| match (1..=MAX_KEY_LENGTH).contains(length) {
+                    true => Ok(()),
+                    false => Err(format!("must be between 1 and {}", MAX_KEY_LENGTH)),
+                })
+                .interact_text()?;
+            let format = Select::with_theme(&theme)
+                .with_prompt("Encoding format")
+                .items(&ENCODING_FORMATS)
+                .default(0)
+                .interact()?;
+            args.extend([
+                "--length".to_string(),
+                length.to_string(),
+                "--format".to_string(),
+                ENCODING_FORMATS[format].to_string(),
+            ]);
+        }
+        _ => {
+            let versions = ["v4", "v7", "v1", "v6", "v3", "v5"];
+            let version = Select::with_theme(&theme)
+                .with_prompt("UUID version")
+                .items(&versions)
+                .default(0)
+                .interact()?;
+            args.extend(["--uuid-version".to_string(), versions[version].to_string()]);
+
+            if matches!(versions[version], "v3" | "v5") {
+                let namespace: String = Input::with_theme(&theme)
+                    .with_prompt("Namespace (a UUID or one of dns, url, oid, x500)")
+                    .default("dns".to_string())
+                    .validate_with(|namespace: &String| parse_namespace(namespace).map(|_| ()))
+                    .interact_text()?;
+                let name: String = Input::with_theme(&theme).with_prompt("Name").interact_text()?;
+                args.extend(["--namespace".to_string(), namespace, "--name".to_string(), name]);
+            }
+
+            let formats = ["hyphenated", "simple", "urn", "braced", "short"];
+            let format = Select::with_theme(&theme)
+                .with_prompt("UUID format")
+                .items(&formats)
+                .default(0)
+                .interact()?;
+            args.extend(["--uuid-format".to_string(), formats[format].to_string()]);
+        }
+    }
+
+    let count: u64 = Input::with_theme(&theme)
+        .with_prompt("How many?")
+        .default(1)
+        .validate_with(|count: &u64| match *count >= 1 {
+            true => Ok(()),
+            false => Err("must be at least 1"),
+        })
+        .interact_text()?;
+    args.extend(["--count".to_string(), count.to_string()]);
+
+    Ok(args)
+}
+
+/// Writes `bytes` to stdout and flushes it.
+fn write_stdout(bytes: &[u8]) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(bytes)?;
+    stdout.flush()
+}
+
+/// Exits if writing the output failed: silently with status 0 if the reader closed the pipe
+/// early (e.g. `genrs | head -c 10`), and with an error message and status 1 otherwise.
+///
+/// Callers wipe their buffers before calling this, as `std::process::exit` skips destructors.
+fn check_written(written: io::Result<()>, target: &str) {
+    match written {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => std::process::exit(0),
+        Err(err) => {
+            eprintln!("Error: failed to write {}: {}", target, err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Drops a buffer holding key material (zeroized with the `zeroize` feature).
+#[cfg(feature = "zeroize")]
+fn wipe_bytes(mut bytes: Vec<u8>) {
+    zeroize::Zeroize::zeroize(&mut bytes);
+}
+
+/// Drops a buffer holding key material (zeroized with the `zeroize` feature).
+#[cfg(not(feature = "zeroize"))]
+fn wipe_bytes(_bytes: Vec<u8>) {}
+
+/// Drops a string holding key material (zeroized with the `zeroize` feature).
+fn wipe_string(value: String) {
+    wipe_bytes(value.into_bytes());
+}
+
+/// Wipes the values generated so far and exits with status 1, after an error has been printed.
+///
+/// `std::process::exit` skips destructors, so the values are wiped explicitly first.
+fn exit_with_failure(generated: Vec<Generated>) -> ! {
+    for generated in generated {
+        wipe_string(generated.value);
+        wipe_json(generated.json);
+    }
+    std::process::exit(1);
+}
+
+/// Drops a JSON value, wiping every string in it with `wipe_string`.
+fn wipe_json(value: Value) {
+    match value {
+        Value::String(value) => wipe_string(value),
+        Value::Array(values) => values.into_iter().for_each(wipe_json),
+        Value::Object(map) => map.into_iter().for_each(|(_, value)| wipe_json(value)),
+        _ => {}
+    }
+}
+
+/// Places `text` on the system clipboard so that secrets never end up in the terminal scrollback.
+fn copy_to_clipboard(text: &str) -> Result<(), arboard::Error> {
+    arboard::Clipboard::new()?.set_text(text)
+}
+
+/// Prints the crate version, the resolved versions of the cryptographic dependencies (recorded
+/// from `Cargo.lock` by the build script) and the randomness backend in use, for audits.
+fn print_build_info(matches: &ArgMatches) {
+    let entropy_source = match matches.get_one::<String>("seed") {
+        Some(_) => "ChaCha20 seeded with --seed (NOT cryptographically secure)".to_string(),
+        None => match matches.get_one::<String>("entropy_source").unwrap().parse::<EntropySource>() {
+            Ok(EntropySource::OsRng) => "OsRng (operating system CSPRNG via getrandom)".to_string(),
+            Ok(source) => format!("device {}", source),
+            Err(err) => format!("invalid ({})", err),
+        },
+    };
+
+    println!("genrs {}", crate_version!());
+    println!("rand: {}", env!("GENRS_RAND_VERSION"));
+    println!("getrandom: {}", env!("GENRS_GETRANDOM_VERSION"));
+    println!("uuid: {}", env!("GENRS_UUID_VERSION"));
+    println!("base64: {}", env!("GENRS_BASE64_VERSION"));
+    println!("hex: {}", env!("GENRS_HEX_VERSION"));
+    println!("entropy source: {}", entropy_source);
+}
+
+/// Prints every key preset with its description and byte length, as a table or as a JSON array.
+fn print_presets(output: &str) {
+    if output == "json" {
+        let presets: Vec<Value> = KeyPreset::ALL
+            .iter()
+            .map(|preset| {
+                json!({
+                    "name": preset.to_string(),
+                    "description": preset.description(),
+                    "length": preset.byte_length(),
+                })
+            })
+            .collect();
+        println!("{}", Value::Array(presets));
+        return;
+    }
+
+    for preset in KeyPreset::ALL {
+        println!("{:<16}{:>3} bytes  {}", preset.to_string(), preset.byte_length(), preset.description());
+    }
+}
+
+/// Prints the resolved configuration to stderr for `--verbose`, so that stdout stays clean for piping.
+fn print_config(settings: &[(&str, String)]) {
+    for (name, value) in settings {
+        eprintln!("{:>14}: {}", name, value);
+    }
+}
+
+/// Prints a warning to stderr if a secret with `bits` of entropy is weak, unless `--no-warn` is set.
+fn warn_if_weak(bits: f64, no_warn: bool) {
+    if no_warn {
+        return;
+    }
+    if let Some(warning) = strength_warning(bits) {
+        eprintln!("Warning: {:.1} bits of entropy is {}", bits, warning);
+    }
+}
+
+/// Accepts names that shells allow for variables: letters, digits and underscores, not starting
+/// with a digit.
+fn parse_env_name(name: &str) -> Result<String, String> {
+    let valid_start = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+    if !valid_start || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!(
+            "'{}' is not a valid variable name; use letters, digits and '_', not starting with a digit",
+            name
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Parses a hex seed of up to 32 bytes (zero-padded) into a seeded ChaCha20 RNG.
+fn parse_seed(seed: &str) -> Result<ChaCha20Rng, String> {
+    let bytes = decode_key(seed, EncodingFormat::Hex).map_err(|err| format!("invalid seed: {}", err))?;
+    if bytes.len() > 32 {
+        return Err(format!("invalid seed: expected at most 32 bytes, got {}", bytes.len()));
+    }
+
+    let mut seed = [0u8; 32];
+    seed[..bytes.len()].copy_from_slice(&bytes);
+    Ok(ChaCha20Rng::from_seed(seed))
+}
+
+/// Reads all of stdin as raw bytes.
+fn read_stdin_bytes() -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    io::stdin().lock().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Writes the output to `path`, creating the file with `0600` permissions on Unix so that
+/// generated secrets are only readable by the current user. With `append`, the output is added to
+/// the end of an existing file, e.g. a `.env` file, instead of replacing it.
+fn write_output_file(path: &Path, contents: &[u8], append: bool) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    match append {
+        true => options.append(true).create(true),
+        false => options.write(true).create(true).truncate(true),
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents)
 }
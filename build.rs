@@ -0,0 +1,53 @@
+//! Records the resolved versions of the cryptographic dependencies for `genrs --build-info`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Dependencies whose resolved versions are reported by `--build-info`.
+const DEPENDENCIES: [&str; 5] = ["rand", "getrandom", "uuid", "base64", "hex"];
+
+fn main() {
+    let lockfile = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.lock");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Cargo.lock is not committed, so it may be missing, e.g. when genrs is built as a
+    // dependency. Watching a missing file would rerun this script on every build.
+    let lock = match fs::read_to_string(&lockfile) {
+        Ok(lock) => {
+            println!("cargo:rerun-if-changed={}", lockfile.display());
+            Some(lock)
+        }
+        Err(_) => None,
+    };
+    let unknown = match lock {
+        Some(_) => "unknown".to_string(),
+        None => format!("unknown (genrs {} was built without Cargo.lock)", env::var("CARGO_PKG_VERSION").unwrap()),
+    };
+    for dependency in DEPENDENCIES {
+        let versions = resolved_versions(lock.as_deref().unwrap_or_default(), dependency);
+        let versions = if versions.is_empty() {
+            unknown.clone()
+        } else {
+            versions.join(", ")
+        };
+        println!("cargo:rustc-env=GENRS_{}_VERSION={}", dependency.to_uppercase(), versions);
+    }
+}
+
+/// Returns every version of `name` listed in the lockfile (there may be several).
+fn resolved_versions(lock: &str, name: &str) -> Vec<String> {
+    let name_line = format!("name = \"{}\"", name);
+    let mut lines = lock.lines();
+    let mut versions = Vec::new();
+    while let Some(line) = lines.next() {
+        if line == name_line {
+            let version = lines
+                .next()
+                .and_then(|line| line.strip_prefix("version = \""))
+                .and_then(|version| version.strip_suffix('"'));
+            versions.extend(version.map(str::to_string));
+        }
+    }
+    versions
+}
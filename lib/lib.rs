@@ -2,30 +2,50 @@
 //!
 //! A versatile key and UUID generation library that allows you to:
 //!
-//! - Generate secure random keys of arbitrary length
-//! - Encode keys in either hexadecimal (`Hex`) or Base64 (`Base64`) format
-//! - Generate UUIDs of any version (V1, V3, V4, V5)
+//! - Generate secure random keys of arbitrary length, optionally from a caller-provided CSPRNG or entropy device
+//! - Look up the key length of common primitives (AES, HMAC, ChaCha20, Ed25519, ...) with `KeyPreset`
+//! - Encode keys in hexadecimal (`Hex`), Base64 (`Base64`, `Base64NoPad`), URL-safe Base64 (`Base64Url`, `Base64UrlNoPad`),
+//!   Base32 (`Base32`, `Base32Crockford`), Base58 (`Base58`), Ascii85 (`Ascii85`), PEM (`Pem`) or bech32 (`Bech32`) format
+//! - Decode previously encoded keys back into raw bytes
+//! - Generate human-typeable passwords from alphanumeric, alphabetic or printable ASCII character sets, optionally
+//!   with a minimum number of uppercase letters, lowercase letters, digits and symbols
+//! - Generate BIP39 mnemonic phrases (12 to 24 words)
+//! - Generate diceware passphrases from the EFF large wordlist
+//! - Calculate the entropy in bits of generated keys, passwords and passphrases
+//! - Generate UUIDs of any version (V1, V3, V4, V5, V6, V7, V8)
 //!
 //! ## Example usage
 //!
 //! ```
+//! # #[cfg(feature = "std")] {
 //! use genrs_lib::{encode_key, generate_key, generate_uuid, EncodingFormat, UuidVersion};
 //!
 //! // Generate a random key
 //! let key = generate_key(32);
-//! let encoded_key = encode_key(key, EncodingFormat::Base64).unwrap();
+//! let encoded_key = encode_key(&key, EncodingFormat::Base64).unwrap();
 //! println!("Generated and encoded key: {}", encoded_key);
 //!
 //! // Generate a UUID V4
 //! let uuid_v4 = generate_uuid(UuidVersion::V4, None, None).unwrap();
 //! println!("Generated UUID V4: {}", uuid_v4);
+//! # }
 //! ```
 //!
 //! ## Features
 //!
 //! - **Key Generation**: Uses a cryptographically secure random number generator (CSPRNG) to generate random keys of arbitrary length.
-//! - **Key Encoding**: Supports `Hex` and `Base64` encoding formats for ease of transmission and storage.
-//! - **UUID Generation**: Create universally unique identifiers (UUIDs) for V1 (timestamp-based), V3 (namespace + name, MD5), V4 (random), and V5 (namespace + name, SHA-1).
+//! - **Key Encoding**: Supports `Hex`, `Base64`, `Base64NoPad`, URL-safe `Base64Url` and `Base64UrlNoPad`, `Base32`,
+//!   Crockford's `Base32Crockford` (optionally with a check symbol), `Base58`, `Ascii85`, `Pem` and `Bech32` encoding
+//!   formats for ease of transmission and storage.
+//! - **UUID Generation**: Create universally unique identifiers (UUIDs) for V1 (timestamp-based), V3 (namespace + name, MD5), V4 (random), V5 (namespace + name, SHA-1), V6 (reordered timestamp), V7 (time-ordered, random), and V8 (custom payload).
+//! - **Serde** (optional `serde` feature): `Serialize`/`Deserialize` for `EncodingFormat` and `UuidVersion`, using the CLI names.
+//! - **Zeroize** (optional `zeroize` feature): Returns keys wrapped in `Zeroizing` so they are wiped from memory on drop.
+//! - **WebAssembly** (optional `wasm` feature): Uses the browser's `crypto.getRandomValues` and exports `generateKey`/`generateUuid` through `wasm-bindgen`.
+//! - **Key Derivation** (optional `kdf` feature): Derives reproducible keys from a passphrase with Argon2id or PBKDF2-HMAC-SHA256.
+//! - **no_std** (disable the default `std` feature): Builds on `core` + `alloc` for embedded targets. Only the
+//!   generators that take a caller-provided RNG (`generate_key_with_rng`, `generate_key_excluding_with_rng`,
+//!   `generate_password_with_rng`, `generate_policy_password_with_rng`, `generate_license_key_with_rng`) and
+//!   their types remain; `OsRng`, encoding, mnemonics, passphrases, entropy estimates and UUIDs require `std`.
 //!
 //! ### Referenced Libraries
 //!
@@ -33,27 +53,285 @@
 //! - [`uuid`](https://docs.rs/uuid/0.8.2/uuid/) for UUID generation.
 //! - [`hex`](https://docs.rs/hex/0.4.2/hex/) for encoding keys in hexadecimal format.
 //! - [`base64`](https://docs.rs/base64/0.13.0/base64/) for encoding keys in Base64 format.
+//! - [`base32`](https://docs.rs/base32/0.4.0/base32/) for encoding keys in Base32 format.
+//! - [`bs58`](https://docs.rs/bs58/0.5.1/bs58/) for encoding keys in Base58 format.
+//! - [`bech32`](https://docs.rs/bech32/0.11.0/bech32/) for encoding keys in bech32 format.
+//! - [`bip39`](https://docs.rs/bip39/2.1.0/bip39/) for the BIP39 English wordlist.
+//! - [`sha2`](https://docs.rs/sha2/0.10.8/sha2/) for the BIP39 checksum.
+//! - [`eff-wordlist`](https://docs.rs/eff-wordlist/1.0.3/eff_wordlist/) for the EFF large diceware wordlist.
+//! - [`zeroize`](https://docs.rs/zeroize/1.8.1/zeroize/) for wiping key material from memory (optional).
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+
+#[cfg(feature = "std")]
 use base64::Engine;
-use rand::{rngs::OsRng, Rng, RngCore};
-use uuid::{Context, Timestamp, Uuid};
+#[cfg(feature = "std")]
+use bip39::Language;
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+use rand::{distributions::Uniform, seq::SliceRandom, CryptoRng, Rng, RngCore};
+#[cfg(feature = "std")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "std")]
+use uuid::{Builder, ContextV1, NoContext, Timestamp, Uuid};
+#[cfg(feature = "zeroize")]
+pub use zeroize::Zeroizing;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Error type returned by the fallible functions of this library.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use genrs_lib::{generate_uuid, GenrsError, UuidVersion};
+///
+/// match generate_uuid(UuidVersion::V5, None, Some("example")) {
+///     Err(GenrsError::MissingNamespace(_)) => println!("A namespace is required"),
+///     Err(err) => eprintln!("Error: {}", err),
+///     Ok(uuid) => println!("Generated UUID V5: {}", uuid),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenrsError {
+    /// The UUID version (e.g. `"V3"`) requires a namespace, but none was given.
+    MissingNamespace(&'static str),
+    /// The UUID version (e.g. `"V3"`) requires a name, but none was given.
+    MissingName(&'static str),
+    /// The requested or supplied length is not valid.
+    InvalidLength(String),
+    /// The requested format is not supported.
+    UnsupportedFormat(String),
+    /// The requested UUID version is not supported.
+    UnsupportedUuidVersion(String),
+    /// The requested character set is not supported.
+    UnsupportedCharset(String),
+    /// The input could not be decoded from the given format.
+    InvalidEncoding(String),
+    /// The requested key preset is not supported.
+    UnsupportedPreset(String),
+    /// The custom alphabet is not a valid encoding alphabet.
+    InvalidAlphabet(String),
+    /// The bech32 human-readable part is empty, too long or contains invalid characters.
+    InvalidHrp(String),
+    /// The namespace is neither a well-known namespace keyword nor a valid UUID.
+    InvalidNamespace(String),
+    /// The entropy source is neither `os` nor a device path.
+    InvalidEntropySource(String),
+    /// The system's entropy source failed to produce random bytes.
+    EntropyUnavailable(String),
+}
+
+impl fmt::Display for GenrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenrsError::MissingNamespace(version) => write!(f, "Namespace is required for UUID {}", version),
+            GenrsError::MissingName(version) => write!(f, "Name is required for UUID {}", version),
+            GenrsError::InvalidLength(msg) => write!(f, "Invalid length: {}", msg),
+            GenrsError::UnsupportedFormat(format) => write!(f, "Unsupported format: {}", format),
+            GenrsError::UnsupportedUuidVersion(version) => write!(f, "Unsupported UUID version: {}", version),
+            GenrsError::UnsupportedCharset(charset) => write!(f, "Unsupported charset: {}", charset),
+            GenrsError::UnsupportedPreset(preset) => write!(f, "Unsupported key preset: {}", preset),
+            GenrsError::InvalidAlphabet(msg) => write!(f, "Invalid alphabet: {}", msg),
+            GenrsError::InvalidHrp(msg) => write!(f, "Invalid bech32 human-readable part: {}", msg),
+            GenrsError::InvalidEncoding(msg) => write!(f, "{}", msg),
+            GenrsError::InvalidNamespace(namespace) => write!(f, "invalid namespace UUID '{}'", namespace),
+            GenrsError::InvalidEntropySource(source) => write!(f, "Invalid entropy source: '{}'", source),
+            GenrsError::EntropyUnavailable(msg) => write!(
+                f,
+                "Failed to generate secure random bytes ({}). \
+                Ensure that the system's entropy source is available and functioning correctly.",
+                msg
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GenrsError {}
 
 /// Enum to represent the encoding format for the key.
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "std")] {
 /// use genrs_lib::{encode_key, generate_key, EncodingFormat};
 ///
 /// let key = generate_key(32);
-/// let encoded_key = encode_key(key, EncodingFormat::Base64).unwrap();
+/// let encoded_key = encode_key(&key, EncodingFormat::Base64).unwrap();
 /// println!("Generated and encoded key: {}", encoded_key);
+/// # }
 /// ```
 ///
 /// Refer to the `encode_key` function for encoding usage.
+///
+/// With the `serde` feature enabled, the unit variants (de)serialize as the lowercase names used
+/// by the CLI (e.g. `"base64url"`), while `Base32Crockford` is represented as
+/// `{"base32crockford": {"checksum": true}}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum EncodingFormat {
     Hex,
     Base64,
+    /// Standard Base64 without the trailing `=` padding.
+    Base64NoPad,
+    /// URL-safe Base64 (`-` and `_` instead of `+` and `/`), with `=` padding.
+    Base64Url,
+    /// URL-safe Base64 without padding, as used in JWT headers and payloads.
+    Base64UrlNoPad,
+    /// RFC 4648 Base32 (uppercase alphabet) without padding, as expected by TOTP/HOTP authenticator apps.
+    Base32,
+    /// Crockford's Base32 alphabet (omits `I`, `L`, `O` and `U`), optionally followed by a check symbol.
+    Base32Crockford { checksum: bool },
+    /// Base58 with the Bitcoin alphabet; leading zero bytes are preserved as leading `1`s.
+    Base58,
+    /// Ascii85 (without the `<~`/`~>` delimiters), using `z` for all-zero groups of four bytes.
+    Ascii85,
+    /// RFC 7468 PEM block labelled `GENRS KEY`, with the Base64 body wrapped at 64 characters.
+    /// Use `encode_pem` for a custom label.
+    Pem,
+    /// BIP 173 bech32 with the human-readable part `genrs` and a 6-character checksum.
+    /// Use `encode_bech32` for a custom human-readable part.
+    Bech32,
+}
+
+impl EncodingFormat {
+    /// Every supported encoding format, in the order they are listed by the CLI.
+    pub const ALL: [EncodingFormat; 12] = [
+        EncodingFormat::Hex,
+        EncodingFormat::Base64,
+        EncodingFormat::Base64NoPad,
+        EncodingFormat::Base64Url,
+        EncodingFormat::Base64UrlNoPad,
+        EncodingFormat::Base32,
+        EncodingFormat::Base32Crockford { checksum: false },
+        EncodingFormat::Base32Crockford { checksum: true },
+        EncodingFormat::Base58,
+        EncodingFormat::Ascii85,
+        EncodingFormat::Pem,
+        EncodingFormat::Bech32,
+    ];
+
+    /// Returns `true` if the format appends `=` padding to the encoded output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::EncodingFormat;
+    ///
+    /// assert!(EncodingFormat::Base64Url.is_padded());
+    /// assert!(!EncodingFormat::Base64UrlNoPad.is_padded());
+    /// ```
+    pub fn is_padded(&self) -> bool {
+        matches!(self, EncodingFormat::Base64 | EncodingFormat::Base64Url)
+    }
+
+    /// Returns `true` if the format's alphabet is case-insensitive, so that its output can be
+    /// uppercased without changing the encoded value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::EncodingFormat;
+    ///
+    /// assert!(EncodingFormat::Hex.is_case_insensitive());
+    /// assert!(EncodingFormat::Base32.is_case_insensitive());
+    /// assert!(EncodingFormat::Bech32.is_case_insensitive());
+    /// assert!(!EncodingFormat::Base64.is_case_insensitive());
+    /// ```
+    pub fn is_case_insensitive(&self) -> bool {
+        matches!(
+            self,
+            EncodingFormat::Hex | EncodingFormat::Base32 | EncodingFormat::Base32Crockford { .. } | EncodingFormat::Bech32
+        )
+    }
+}
+
+impl fmt::Display for EncodingFormat {
+    /// Formats the encoding as the canonical lowercase name accepted by `FromStr` and the CLI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::EncodingFormat;
+    ///
+    /// let format = EncodingFormat::Base32Crockford { checksum: true };
+    /// assert_eq!(format.to_string(), "base32crockfordcheck");
+    /// assert_eq!(format.to_string().parse::<EncodingFormat>().unwrap(), format);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EncodingFormat::Hex => "hex",
+            EncodingFormat::Base64 => "base64",
+            EncodingFormat::Base64NoPad => "base64nopad",
+            EncodingFormat::Base64Url => "base64url",
+            EncodingFormat::Base64UrlNoPad => "base64urlnopad",
+            EncodingFormat::Base32 => "base32",
+            EncodingFormat::Base32Crockford { checksum: false } => "base32crockford",
+            EncodingFormat::Base32Crockford { checksum: true } => "base32crockfordcheck",
+            EncodingFormat::Base58 => "base58",
+            EncodingFormat::Ascii85 => "ascii85",
+            EncodingFormat::Pem => "pem",
+            EncodingFormat::Bech32 => "bech32",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for EncodingFormat {
+    type Err = GenrsError;
+
+    /// Parses the lowercase format names used by the CLI, e.g. `hex`, `base64` or `base32crockfordcheck`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::EncodingFormat;
+    ///
+    /// assert_eq!("base64".parse::<EncodingFormat>().unwrap(), EncodingFormat::Base64);
+    /// assert!("base65".parse::<EncodingFormat>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hex" => Ok(EncodingFormat::Hex),
+            "base64" => Ok(EncodingFormat::Base64),
+            "base64nopad" => Ok(EncodingFormat::Base64NoPad),
+            "base64url" => Ok(EncodingFormat::Base64Url),
+            "base64urlnopad" => Ok(EncodingFormat::Base64UrlNoPad),
+            "base32" => Ok(EncodingFormat::Base32),
+            "base32crockford" => Ok(EncodingFormat::Base32Crockford { checksum: false }),
+            "base32crockfordcheck" => Ok(EncodingFormat::Base32Crockford { checksum: true }),
+            "base58" => Ok(EncodingFormat::Base58),
+            "ascii85" => Ok(EncodingFormat::Ascii85),
+            "pem" => Ok(EncodingFormat::Pem),
+            "bech32" => Ok(EncodingFormat::Bech32),
+            _ => Err(GenrsError::UnsupportedFormat(s.to_string())),
+        }
+    }
 }
 
 /// Generates a random key of the given length in bytes.
@@ -72,106 +350,3602 @@ pub enum EncodingFormat {
 ///
 /// # Panics
 ///
-/// Will panic if the system's entropy source is unavailable.
+/// Will panic if `length` is 0 or if the system's entropy source is unavailable. Use
+/// `try_generate_key` to handle these cases instead.
 ///
 /// Refer to the `encode_key` function for encoding the generated key.
+#[cfg(feature = "std")]
 pub fn generate_key(length: usize) -> Vec<u8> {
+    try_generate_key(length).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Generates a random key of the given length in bytes, returning an error instead of panicking
+/// if the system's entropy source is unavailable.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::try_generate_key;
+///
+/// let key = try_generate_key(32).unwrap();
+/// assert_eq!(key.len(), 32);
+///
+/// assert!(try_generate_key(0).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if `length` is 0, since an empty key is almost always a
+/// misconfiguration, or larger than `MAX_KEY_LENGTH`, and `GenrsError::EntropyUnavailable` if the
+/// system's entropy source fails.
+#[cfg(feature = "std")]
+pub fn try_generate_key(length: usize) -> Result<Vec<u8>, GenrsError> {
+    try_generate_key_with_max(length, MAX_KEY_LENGTH)
+}
+
+/// The default upper bound for key lengths accepted by the fallible key generators (4 MiB).
+pub const MAX_KEY_LENGTH: usize = 4 * 1024 * 1024;
+
+/// Generates a random key like `try_generate_key`, with a caller-provided upper bound on the
+/// length instead of `MAX_KEY_LENGTH`.
+///
+/// The length is checked before anything is allocated, so a bad length from untrusted input
+/// (e.g. an environment variable) cannot exhaust the process's memory.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::try_generate_key_with_max;
+///
+/// assert_eq!(try_generate_key_with_max(64, 64).unwrap().len(), 64);
+/// assert!(try_generate_key_with_max(65, 64).is_err());
+/// assert!(try_generate_key_with_max(usize::MAX, 64).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if `length` is 0 or larger than `max_length`, and
+/// `GenrsError::EntropyUnavailable` if the system's entropy source fails.
+#[cfg(feature = "std")]
+pub fn try_generate_key_with_max(length: usize, max_length: usize) -> Result<Vec<u8>, GenrsError> {
+    validate_key_length(length, max_length)?;
+    let mut key = vec![0u8; length];
+    generate_key_into(&mut key)?;
+    Ok(key)
+}
+
+/// Rejects key lengths of 0 bytes or more than `max_length` bytes.
+#[cfg(feature = "std")]
+fn validate_key_length(length: usize, max_length: usize) -> Result<(), GenrsError> {
+    if length == 0 {
+        return Err(GenrsError::InvalidLength("a key must be at least 1 byte long".to_string()));
+    }
+    if length > max_length {
+        return Err(GenrsError::InvalidLength(format!(
+            "a key must be at most {} bytes long, got {}",
+            max_length, length
+        )));
+    }
+    Ok(())
+}
+
+/// Fills the caller-provided buffer with secure random bytes.
+///
+/// This avoids allocating a new `Vec` for every key, e.g. when minting many keys into a reused buffer.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_into;
+///
+/// let mut buf = [0u8; 32];
+/// for _ in 0..3 {
+///     generate_key_into(&mut buf).unwrap();
+///     println!("{:02x?}", buf);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::EntropyUnavailable` if the system's entropy source fails.
+#[cfg(feature = "std")]
+pub fn generate_key_into(buf: &mut [u8]) -> Result<(), GenrsError> {
+    OsRng
+        .try_fill_bytes(buf)
+        .map_err(|err| GenrsError::EntropyUnavailable(err.to_string()))
+}
+
+/// Returns `true` if the system's entropy source can produce random bytes.
+///
+/// This probes `OsRng` once with a small `try_fill_bytes` call, so callers can fail fast with a
+/// clear diagnostic instead of hitting a panic from `generate_key` midway through their work.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{entropy_available, generate_key};
+///
+/// if entropy_available() {
+///     let key = generate_key(32);
+///     assert_eq!(key.len(), 32);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn entropy_available() -> bool {
+    let mut probe = [0u8; 16];
+    generate_key_into(&mut probe).is_ok()
+}
+
+/// Generates a random key with a length known at compile time, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_array;
+///
+/// let key: [u8; 32] = generate_key_array();
+/// assert_eq!(key.len(), 32);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_key_array<const N: usize>() -> [u8; N] {
+    let mut key = [0u8; N];
+    generate_key_into(&mut key).unwrap_or_else(|err| panic!("{}", err));
+    key
+}
+
+/// Generates a random API token: `byte_len` secure random bytes encoded as URL-safe Base64
+/// without padding.
+///
+/// The token can be used in URLs, HTTP headers and environment variables without escaping. Use
+/// at least 32 bytes (256 bits) for long-lived credentials. On the command line,
+/// `genrs --preset apikey256 --format base64urlnopad` prints tokens of the same shape.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{decode_key, generate_api_token, EncodingFormat};
+///
+/// let token = generate_api_token(32);
+/// assert_eq!(token.len(), 43);
+/// assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+/// assert_eq!(decode_key(&token, EncodingFormat::Base64UrlNoPad).unwrap().len(), 32);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if `byte_len` is 0 or if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_api_token(byte_len: usize) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(generate_key(byte_len))
+}
+
+/// Generates a random key of the given length in bytes and returns it together with its
+/// encoding in the given format.
+///
+/// This keeps the raw bytes available, e.g. to provision them into a keystore, while the encoded
+/// form is logged or displayed.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{decode_key, generate_encoded_key, EncodingFormat};
+///
+/// let (key, encoded) = generate_encoded_key(32, EncodingFormat::Base64);
+/// assert_eq!(key.len(), 32);
+/// assert_eq!(decode_key(&encoded, EncodingFormat::Base64).unwrap(), key);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if `length` is 0 or larger than `MAX_KEY_LENGTH`, if the key is too long for the
+/// format (e.g. `Bech32`), or if the system's entropy source is unavailable. Use
+/// `try_generate_encoded_key` to handle these cases instead.
+#[cfg(feature = "std")]
+pub fn generate_encoded_key(length: usize, format: EncodingFormat) -> (Vec<u8>, String) {
+    try_generate_encoded_key(length, format).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Generates a random key and its encoding like `generate_encoded_key`, returning an error
+/// instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{try_generate_encoded_key, EncodingFormat};
+///
+/// let (key, encoded) = try_generate_encoded_key(16, EncodingFormat::Hex).unwrap();
+/// assert_eq!(hex::encode(&key), encoded);
+///
+/// assert!(try_generate_encoded_key(0, EncodingFormat::Hex).is_err());
+/// assert!(try_generate_encoded_key(64, EncodingFormat::Bech32).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns the errors of `try_generate_key` and `encode_key`.
+#[cfg(feature = "std")]
+pub fn try_generate_encoded_key(length: usize, format: EncodingFormat) -> Result<(Vec<u8>, String), GenrsError> {
+    let key = try_generate_key(length)?;
+    let encoded = encode_key(&key, format)?;
+    Ok((key, encoded))
+}
+
+/// Generates `count` random keys of `each_len` bytes packed into one contiguous buffer.
+///
+/// The whole buffer is filled with a single `OsRng` call, which avoids one allocation and one RNG
+/// call per key when minting large batches. Key `i` occupies the bytes
+/// `i * each_len..(i + 1) * each_len`, so callers index the buffer with an `each_len` stride,
+/// e.g. via `chunks_exact(each_len)`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_keys_packed;
+///
+/// let packed = generate_keys_packed(1000, 32);
+/// assert_eq!(packed.len(), 1000 * 32);
+///
+/// let third_key = &packed[2 * 32..3 * 32];
+/// assert_eq!(third_key.len(), 32);
+///
+/// for key in packed.chunks_exact(32) {
+///     assert_eq!(key.len(), 32);
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Will panic if `count * each_len` overflows `usize` or if the system's entropy source is
+/// unavailable.
+#[cfg(feature = "std")]
+pub fn generate_keys_packed(count: usize, each_len: usize) -> Vec<u8> {
+    let total = count
+        .checked_mul(each_len)
+        .unwrap_or_else(|| panic!("{} keys of {} bytes overflow the address space", count, each_len));
+    let mut packed = vec![0u8; total];
+    generate_key_into(&mut packed).unwrap_or_else(|err| panic!("{}", err));
+    packed
+}
+
+/// Generates a random key of the given length in bytes using the provided RNG.
+///
+/// The RNG must implement `CryptoRng`, so only cryptographically secure generators can be used.
+/// Passing a seeded generator makes the output reproducible, which is useful in tests.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_with_rng;
+/// use rand_chacha::rand_core::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+///
+/// let first = generate_key_with_rng(32, &mut ChaCha20Rng::seed_from_u64(42));
+/// let second = generate_key_with_rng(32, &mut ChaCha20Rng::seed_from_u64(42));
+/// assert_eq!(first, second);
+/// ```
+///
+/// The `CryptoRng` bound rejects non-cryptographic generators at compile time:
+///
+/// ```compile_fail
+/// use genrs_lib::generate_key_with_rng;
+/// use rand::RngCore;
+///
+/// struct Counter(u64);
+///
+/// impl RngCore for Counter {
+///     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+///     fn next_u64(&mut self) -> u64 { self.0 += 1; self.0 }
+///     fn fill_bytes(&mut self, dest: &mut [u8]) { rand::rand_core::impls::fill_bytes_via_next(self, dest) }
+///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> { Ok(self.fill_bytes(dest)) }
+/// }
+///
+/// let key = generate_key_with_rng(32, &mut Counter(0));
+/// ```
+///
+/// Use `generate_key_with_insecure_rng` for reproducible test fixtures from such generators.
+///
+/// # Panics
+///
+/// Will panic if the RNG fails to produce random bytes.
+pub fn generate_key_with_rng<R: RngCore + CryptoRng>(length: usize, rng: &mut R) -> Vec<u8> {
+    key_from_rng(length, rng)
+}
+
+/// Generates a key of the given length in bytes from any RNG, including non-cryptographic ones.
+///
+/// **WARNING: the output is only as unpredictable as `rng`.** This function deliberately drops
+/// the `CryptoRng` bound of `generate_key_with_rng` so that tests and benchmarks can use cheap,
+/// seeded generators such as `rand::rngs::SmallRng` or a fixed counter. Never use it for keys that
+/// protect anything.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_with_insecure_rng;
+/// use rand::rngs::mock::StepRng;
+///
+/// let fixture = generate_key_with_insecure_rng(16, &mut StepRng::new(0, 1));
+/// assert_eq!(fixture.len(), 16);
+/// assert_eq!(fixture, generate_key_with_insecure_rng(16, &mut StepRng::new(0, 1)));
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the RNG fails to produce random bytes.
+pub fn generate_key_with_insecure_rng<R: RngCore>(length: usize, rng: &mut R) -> Vec<u8> {
+    key_from_rng(length, rng)
+}
+
+/// Fills a new key of `length` bytes from `rng`, without any requirement on the RNG's quality.
+fn key_from_rng<R: RngCore>(length: usize, rng: &mut R) -> Vec<u8> {
     let mut key = vec![0u8; length];
-    OsRng.try_fill_bytes(&mut key).expect(
+    rng.try_fill_bytes(&mut key).expect(
         "Failed to generate secure random bytes. \
         Ensure that the system's entropy source is available and functioning correctly.",
     );
     key
 }
 
-/// Encodes the given key into the specified format (`Hex` or `Base64`).
+/// Generates a random key of the given length in bytes that contains none of the `forbidden`
+/// bytes, e.g. no `0x00` for protocols that treat it as a terminator.
+///
+/// Every forbidden byte is rejected and drawn again from `OsRng`. With `n` distinct forbidden
+/// byte values (duplicates in `forbidden` count once), the key bytes are uniformly distributed
+/// over the remaining `256 - n` values without any modulo bias. The entropy is
+/// `length * log2(256 - n)` bits, and each byte takes `256 / (256 - n)` draws on average:
+/// negligible for a few forbidden bytes, but twice as many draws when half of the values are
+/// forbidden.
 ///
 /// # Examples
 ///
 /// ```
-/// use genrs_lib::{encode_key, generate_key, EncodingFormat};
+/// use genrs_lib::generate_key_excluding;
 ///
-/// let key = generate_key(16);
-/// let encoded_key = encode_key(key, EncodingFormat::Hex).unwrap();
-/// println!("Hex encoded key: {}", encoded_key);
+/// let key = generate_key_excluding(64, &[0x00, b'\n']);
+/// assert_eq!(key.len(), 64);
+/// assert!(!key.contains(&0x00) && !key.contains(&b'\n'));
+///
+/// let printable: Vec<u8> = (0..=255).filter(|byte: &u8| !byte.is_ascii_graphic()).collect();
+/// assert!(generate_key_excluding(32, &printable).iter().all(u8::is_ascii_graphic));
 /// ```
 ///
-/// # Errors
+/// # Panics
 ///
-/// Returns an error if the format is unsupported. However, this should never happen,
-/// as the format is now restricted to the `EncodingFormat` enum.
-pub fn encode_key(key: Vec<u8>, format: EncodingFormat) -> Result<String, String> {
-    match format {
-        EncodingFormat::Hex => Ok(hex::encode(key)),
-        EncodingFormat::Base64 => Ok(base64::engine::general_purpose::STANDARD.encode(key)),
+/// Will panic if `length` is 0 or larger than `MAX_KEY_LENGTH`, if every byte value is forbidden
+/// or if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_key_excluding(length: usize, forbidden: &[u8]) -> Vec<u8> {
+    validate_key_length(length, MAX_KEY_LENGTH).unwrap_or_else(|err| panic!("{}", err));
+    generate_key_excluding_with_rng(length, forbidden, &mut OsRng)
+}
+
+/// Generates a key without the `forbidden` bytes like `generate_key_excluding`, using the
+/// provided RNG.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_excluding_with_rng;
+/// use rand_chacha::rand_core::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+///
+/// let first = generate_key_excluding_with_rng(32, &[0x00], &mut ChaCha20Rng::seed_from_u64(3));
+/// let second = generate_key_excluding_with_rng(32, &[0x00], &mut ChaCha20Rng::seed_from_u64(3));
+/// assert_eq!(first, second);
+/// assert!(!first.contains(&0x00));
+/// ```
+///
+/// # Panics
+///
+/// Will panic if every byte value is forbidden or if the RNG fails to produce random bytes.
+pub fn generate_key_excluding_with_rng<R: RngCore + CryptoRng>(length: usize, forbidden: &[u8], rng: &mut R) -> Vec<u8> {
+    let mut is_forbidden = [false; 256];
+    for &byte in forbidden {
+        is_forbidden[byte as usize] = true;
     }
+    assert!(is_forbidden.contains(&false), "every byte value is forbidden");
+
+    let mut key = key_from_rng(length, rng);
+    let mut replacement = [0u8; 1];
+    for byte in key.iter_mut() {
+        while is_forbidden[*byte as usize] {
+            rng.try_fill_bytes(&mut replacement).expect(
+                "Failed to generate secure random bytes. \
+                Ensure that the system's entropy source is available and functioning correctly.",
+            );
+            *byte = replacement[0];
+        }
+    }
+    key
 }
 
-/// Enum to represent UUID versions.
+/// Enum to represent where the random bytes of a key come from.
 ///
 /// # Examples
 ///
 /// ```
-/// use genrs_lib::{generate_uuid, UuidVersion};
+/// use std::path::PathBuf;
+/// use genrs_lib::EntropySource;
 ///
-/// let uuid_v4 = generate_uuid(UuidVersion::V4, None, None).unwrap();
-/// println!("Generated UUID V4: {}", uuid_v4);
+/// assert_eq!("os".parse::<EntropySource>().unwrap(), EntropySource::OsRng);
+/// assert_eq!(
+///     "/dev/urandom".parse::<EntropySource>().unwrap(),
+///     EntropySource::File(PathBuf::from("/dev/urandom"))
+/// );
 /// ```
 ///
-/// Refer to the `generate_uuid` function for usage.
-pub enum UuidVersion {
-    V1,
-    V3,
-    V4,
-    V5,
+/// Refer to the `generate_key_from_source` function for usage.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum EntropySource {
+    /// The operating system's CSPRNG via `OsRng`, as used by `generate_key`.
+    #[default]
+    OsRng,
+    /// A device or file to read the bytes from, such as `/dev/urandom` or a hardware RNG device.
+    File(PathBuf),
 }
 
-/// Generates a UUID of the specified version.
+#[cfg(feature = "std")]
+impl fmt::Display for EntropySource {
+    /// Formats the source as accepted by `FromStr`: `os` or the path of the device.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntropySource::OsRng => f.write_str("os"),
+            EntropySource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for EntropySource {
+    type Err = GenrsError;
+
+    /// Parses `os` (case-insensitive) as `OsRng`, and anything else as the path of a device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::{EntropySource, GenrsError};
+    ///
+    /// assert_eq!("OS".parse::<EntropySource>(), Ok(EntropySource::OsRng));
+    /// assert_eq!("".parse::<EntropySource>(), Err(GenrsError::InvalidEntropySource(String::new())));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "os" | "osrng" => Ok(EntropySource::OsRng),
+            "" => Err(GenrsError::InvalidEntropySource(s.to_string())),
+            _ => Ok(EntropySource::File(PathBuf::from(s))),
+        }
+    }
+}
+
+/// Generates a random key of the given length in bytes from the given entropy source.
 ///
-/// - **UUID V1**: Generates a UUID based on the current system time and a random node ID.
-/// - **UUID V3 and V5**: Require a namespace and name for generating a UUID based on the MD5 or SHA-1 hash.
-/// - **UUID V4**: Generates a purely random UUID.
+/// With `EntropySource::OsRng` this behaves like `try_generate_key`. With
+/// `EntropySource::File`, exactly `length` bytes are read from the start of the device, which
+/// must therefore be a character device such as `/dev/urandom` rather than a regular file.
 ///
 /// # Examples
 ///
 /// ```
-/// use uuid::Uuid;
-/// use genrs_lib::{generate_uuid, UuidVersion};
+/// use genrs_lib::{generate_key_from_source, EntropySource};
 ///
-/// let uuid_v1 = generate_uuid(UuidVersion::V1, None, None).unwrap();
-/// println!("Generated UUID V1: {}", uuid_v1);
+/// let key = generate_key_from_source(32, &EntropySource::OsRng).unwrap();
+/// assert_eq!(key.len(), 32);
 ///
-/// let namespace = Uuid::new_v4();
-/// let uuid_v3 = generate_uuid(UuidVersion::V3, Some(namespace), Some("example")).unwrap();
-/// println!("Generated UUID V3: {}", uuid_v3);
+/// let missing = EntropySource::File("/nonexistent/random".into());
+/// assert!(generate_key_from_source(32, &missing).is_err());
 /// ```
 ///
 /// # Errors
 ///
-/// Returns an error if the required parameters (namespace, name) for UUID V3 or V5 are missing.
-pub fn generate_uuid(version: UuidVersion, namespace: Option<Uuid>, name: Option<&str>) -> Result<Uuid, String> {
-    match version {
-        UuidVersion::V1 => {
-            let context = Context::new(OsRng.next_u64() as u16);
-            let ts = Timestamp::now(&context);
-            let node_id: [u8; 6] = OsRng.gen();
+/// Returns `GenrsError::InvalidLength` if `length` is 0 or larger than `MAX_KEY_LENGTH`, and
+/// `GenrsError::EntropyUnavailable` if the source cannot be opened or does not provide enough bytes.
+#[cfg(feature = "std")]
+pub fn generate_key_from_source(length: usize, source: &EntropySource) -> Result<Vec<u8>, GenrsError> {
+    validate_key_length(length, MAX_KEY_LENGTH)?;
+    match source {
+        EntropySource::OsRng => try_generate_key(length),
+        EntropySource::File(path) => {
+            let mut key = vec![0u8; length];
+            File::open(path)
+                .and_then(|mut file| file.read_exact(&mut key))
+                .map_err(|err| GenrsError::EntropyUnavailable(format!("cannot read from '{}': {}", path.display(), err)))?;
+            Ok(key)
+        }
+    }
+}
+
+/// Enum for common key presets, mapping a primitive to the key length it expects.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use genrs_lib::{generate_key, KeyPreset};
+///
+/// let preset: KeyPreset = "aes256".parse().unwrap();
+/// assert_eq!(preset, KeyPreset::Aes256);
+/// assert_eq!(preset.description(), "AES-256");
+///
+/// let key = generate_key(preset.byte_length());
+/// assert_eq!(key.len(), 32);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPreset {
+    Aes128,
+    Aes192,
+    Aes256,
+    HmacSha256,
+    HmacSha512,
+    Jwt256,
+    Jwt512,
+    ApiKey128,
+    ApiKey256,
+    ChaCha20,
+    XChaCha20Nonce,
+    AesGcmNonce,
+    Ed25519Seed,
+}
 
-            Ok(Uuid::new_v1(ts, &node_id))
+impl KeyPreset {
+    /// Every supported preset, in the order they are listed by the CLI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::KeyPreset;
+    ///
+    /// for preset in KeyPreset::ALL {
+    ///     assert_eq!(preset.to_string().parse::<KeyPreset>().unwrap(), preset);
+    /// }
+    /// ```
+    pub const ALL: [KeyPreset; 13] = [
+        KeyPreset::Aes128,
+        KeyPreset::Aes192,
+        KeyPreset::Aes256,
+        KeyPreset::HmacSha256,
+        KeyPreset::HmacSha512,
+        KeyPreset::Jwt256,
+        KeyPreset::Jwt512,
+        KeyPreset::ApiKey128,
+        KeyPreset::ApiKey256,
+        KeyPreset::ChaCha20,
+        KeyPreset::XChaCha20Nonce,
+        KeyPreset::AesGcmNonce,
+        KeyPreset::Ed25519Seed,
+    ];
+
+    /// Returns the key length in bytes for this preset.
+    pub fn byte_length(&self) -> usize {
+        match self {
+            KeyPreset::AesGcmNonce => 12,
+            KeyPreset::Aes128 | KeyPreset::ApiKey128 => 16,
+            KeyPreset::Aes192 | KeyPreset::XChaCha20Nonce => 24,
+            KeyPreset::Aes256
+            | KeyPreset::HmacSha256
+            | KeyPreset::Jwt256
+            | KeyPreset::ApiKey256
+            | KeyPreset::ChaCha20
+            | KeyPreset::Ed25519Seed => 32,
+            KeyPreset::HmacSha512 | KeyPreset::Jwt512 => 64,
         }
-        UuidVersion::V3 => {
-            if let (Some(namespace), Some(name)) = (namespace, name) {
-                Ok(Uuid::new_v3(&namespace, name.as_bytes()))
-            } else {
-                Err("Namespace and name are required for UUID V3".to_string())
-            }
+    }
+
+    /// Returns a human-readable description of the preset, e.g. `HMAC-SHA256`.
+    pub fn description(&self) -> &'static str {
+        match self {
+            KeyPreset::Aes128 => "AES-128",
+            KeyPreset::Aes192 => "AES-192",
+            KeyPreset::Aes256 => "AES-256",
+            KeyPreset::HmacSha256 => "HMAC-SHA256",
+            KeyPreset::HmacSha512 => "HMAC-SHA512",
+            KeyPreset::Jwt256 => "JWT-256",
+            KeyPreset::Jwt512 => "JWT-512",
+            KeyPreset::ApiKey128 => "API Key 128-bit",
+            KeyPreset::ApiKey256 => "API Key 256-bit",
+            KeyPreset::ChaCha20 => "ChaCha20",
+            KeyPreset::XChaCha20Nonce => "XChaCha20 nonce",
+            KeyPreset::AesGcmNonce => "AES-GCM nonce",
+            KeyPreset::Ed25519Seed => "Ed25519 seed",
         }
-        UuidVersion::V4 => Ok(Uuid::new_v4()),
-        UuidVersion::V5 => {
-            if let (Some(namespace), Some(name)) = (namespace, name) {
-                Ok(Uuid::new_v5(&namespace, name.as_bytes()))
-            } else {
-                Err("Namespace and name are required for UUID V5".to_string())
-            }
+    }
+}
+
+impl fmt::Display for KeyPreset {
+    /// Formats the preset as the lowercase name used by the CLI, e.g. `hmac256`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            KeyPreset::Aes128 => "aes128",
+            KeyPreset::Aes192 => "aes192",
+            KeyPreset::Aes256 => "aes256",
+            KeyPreset::HmacSha256 => "hmac256",
+            KeyPreset::HmacSha512 => "hmac512",
+            KeyPreset::Jwt256 => "jwt256",
+            KeyPreset::Jwt512 => "jwt512",
+            KeyPreset::ApiKey128 => "apikey128",
+            KeyPreset::ApiKey256 => "apikey256",
+            KeyPreset::ChaCha20 => "chacha20",
+            KeyPreset::XChaCha20Nonce => "xchacha20nonce",
+            KeyPreset::AesGcmNonce => "aesgcmnonce",
+            KeyPreset::Ed25519Seed => "ed25519seed",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for KeyPreset {
+    type Err = GenrsError;
+
+    /// Parses the lowercase names used by the CLI, e.g. `aes256` or `ed25519seed`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::KeyPreset;
+    ///
+    /// assert_eq!("HMAC512".parse::<KeyPreset>().unwrap(), KeyPreset::HmacSha512);
+    /// assert_eq!(KeyPreset::XChaCha20Nonce.to_string().parse::<KeyPreset>().unwrap(), KeyPreset::XChaCha20Nonce);
+    /// assert!("aes512".parse::<KeyPreset>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "aes128" => Ok(KeyPreset::Aes128),
+            "aes192" => Ok(KeyPreset::Aes192),
+            "aes256" => Ok(KeyPreset::Aes256),
+            "hmac256" => Ok(KeyPreset::HmacSha256),
+            "hmac512" => Ok(KeyPreset::HmacSha512),
+            "jwt256" => Ok(KeyPreset::Jwt256),
+            "jwt512" => Ok(KeyPreset::Jwt512),
+            "apikey128" => Ok(KeyPreset::ApiKey128),
+            "apikey256" => Ok(KeyPreset::ApiKey256),
+            "chacha20" => Ok(KeyPreset::ChaCha20),
+            "xchacha20nonce" => Ok(KeyPreset::XChaCha20Nonce),
+            "aesgcmnonce" => Ok(KeyPreset::AesGcmNonce),
+            "ed25519seed" => Ok(KeyPreset::Ed25519Seed),
+            _ => Err(GenrsError::UnsupportedPreset(s.to_string())),
+        }
+    }
+}
+
+/// Encodes the given key into the specified format (`Hex`, `Base64`, `Base64NoPad`, `Base64Url`,
+/// `Base64UrlNoPad`, `Base32`, `Base32Crockford`, `Base58`, `Ascii85`, `Pem` or `Bech32`).
+///
+/// The key is only borrowed, so the raw bytes remain available to the caller after encoding.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_key, generate_key, EncodingFormat};
+///
+/// let key = generate_key(16);
+/// let encoded_key = encode_key(&key, EncodingFormat::Hex).unwrap();
+/// println!("Hex encoded key: {}", encoded_key);
+/// assert_eq!(key.len(), 16);
+///
+/// let url_safe = encode_key([0xfb_u8, 0xff], EncodingFormat::Base64Url).unwrap();
+/// assert_eq!(url_safe, "-_8=");
+///
+/// let unpadded = encode_key([0xfb_u8, 0xff], EncodingFormat::Base64UrlNoPad).unwrap();
+/// assert_eq!(unpadded, "-_8");
+///
+/// let no_pad = encode_key(generate_key(32), EncodingFormat::Base64NoPad).unwrap();
+/// assert_eq!(no_pad.len(), 43);
+/// assert!(!no_pad.contains('='));
+///
+/// let base32 = encode_key(b"foobar", EncodingFormat::Base32).unwrap();
+/// assert_eq!(base32, "MZXW6YTBOI");
+///
+/// let crockford = encode_key(b"foobar", EncodingFormat::Base32Crockford { checksum: true }).unwrap();
+/// assert_eq!(crockford, "CSQPYRK1E8R");
+///
+/// let base58 = encode_key([0x00_u8, 0x00, 0x61], EncodingFormat::Base58).unwrap();
+/// assert_eq!(base58, "112g");
+///
+/// let ascii85 = encode_key(b"Man is distinguished", EncodingFormat::Ascii85).unwrap();
+/// assert_eq!(ascii85, "9jqo^BlbD-BleB1DJ+*+F(f,q");
+/// assert_eq!(encode_key([0u8; 5], EncodingFormat::Ascii85).unwrap(), "z!!");
+///
+/// let bech32 = encode_key(b"foobar", EncodingFormat::Bech32).unwrap();
+/// assert_eq!(bech32, "genrs1vehk7cnpwgxsafmh");
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if the format cannot represent a key of this length (see
+/// `validate_for_format`).
+#[cfg(feature = "std")]
+pub fn encode_key(key: impl AsRef<[u8]>, format: EncodingFormat) -> Result<String, GenrsError> {
+    let key = key.as_ref();
+    validate_for_format(key.len(), format)?;
+    match format {
+        EncodingFormat::Hex => Ok(hex::encode(key)),
+        EncodingFormat::Base64 => Ok(base64::engine::general_purpose::STANDARD.encode(key)),
+        EncodingFormat::Base64NoPad => Ok(base64::engine::general_purpose::STANDARD_NO_PAD.encode(key)),
+        EncodingFormat::Base64Url => Ok(base64::engine::general_purpose::URL_SAFE.encode(key)),
+        EncodingFormat::Base64UrlNoPad => Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(key)),
+        EncodingFormat::Base32 => Ok(base32::encode(base32::Alphabet::RFC4648 { padding: false }, key)),
+        EncodingFormat::Base32Crockford { checksum } => {
+            let encoded = base32::encode(base32::Alphabet::Crockford, key);
+            if !checksum {
+                return Ok(encoded);
+            }
+            // Build the result in a single allocation so that growing the string does not leave
+            // a stale copy of the encoded key behind.
+            let mut with_check = String::with_capacity(encoded.len() + 1);
+            with_check.push_str(&encoded);
+            with_check.push(crockford_checksum(&encoded));
+            wipe(encoded);
+            Ok(with_check)
+        }
+        EncodingFormat::Base58 => Ok(bs58::encode(key).into_string()),
+        EncodingFormat::Ascii85 => Ok(encode_ascii85(key)),
+        EncodingFormat::Pem => Ok(encode_pem(key, DEFAULT_PEM_LABEL)),
+        EncodingFormat::Bech32 => encode_bech32(key, DEFAULT_BECH32_HRP),
+    }
+}
+
+/// Encodes bytes as Ascii85: every group of four bytes becomes five characters in `!`..=`u`,
+/// an all-zero group becomes `z`, and a final partial group of `n` bytes becomes `n + 1` characters.
+#[cfg(feature = "std")]
+fn encode_ascii85(key: &[u8]) -> String {
+    let mut encoded = String::with_capacity(key.len().div_ceil(4) * 5);
+    for chunk in key.chunks(4) {
+        if chunk == [0, 0, 0, 0] {
+            encoded.push('z');
+            continue;
+        }
+
+        let mut group = [0u8; 4];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(group);
+        let mut digits = [0u8; 5];
+        for digit in digits.iter_mut().rev() {
+            *digit = b'!' + (value % 85) as u8;
+            value /= 85;
+        }
+        encoded.extend(digits[..chunk.len() + 1].iter().map(|&digit| digit as char));
+    }
+    encoded
+}
+
+/// Decodes Ascii85 produced by `encode_ascii85`, ignoring whitespace and the optional `<~`/`~>` delimiters.
+#[cfg(feature = "std")]
+fn decode_ascii85(encoded: &str) -> Result<Vec<u8>, GenrsError> {
+    let invalid = |reason: String| GenrsError::InvalidEncoding(format!("Invalid Ascii85 input: {}", reason));
+    let trimmed = encoded.trim();
+    let body = trimmed.strip_prefix("<~").unwrap_or(trimmed);
+    let body = body.strip_suffix("~>").unwrap_or(body);
+
+    let mut decoded = Vec::with_capacity(body.len() / 5 * 4 + 4);
+    let mut group = [0u8; 5];
+    let mut filled = 0;
+    for c in body.chars().filter(|c| !c.is_ascii_whitespace()) {
+        match c {
+            'z' if filled == 0 => decoded.extend_from_slice(&[0; 4]),
+            '!'..='u' => {
+                group[filled] = c as u8 - b'!';
+                filled += 1;
+                if filled == 5 {
+                    decoded.extend_from_slice(&ascii85_group(&group).ok_or_else(|| invalid("group overflows 32 bits".to_string()))?);
+                    filled = 0;
+                }
+            }
+            _ => return Err(invalid(format!("unexpected character '{}'", c))),
+        }
+    }
+
+    match filled {
+        0 => {}
+        1 => return Err(invalid("trailing group of a single character".to_string())),
+        _ => {
+            // Pad the final partial group with the highest digit and keep `filled - 1` bytes.
+            group[filled..].fill(84);
+            let bytes = ascii85_group(&group).ok_or_else(|| invalid("group overflows 32 bits".to_string()))?;
+            decoded.extend_from_slice(&bytes[..filled - 1]);
+        }
+    }
+    Ok(decoded)
+}
+
+/// Converts five base-85 digits into four bytes, or `None` if the value exceeds `u32::MAX`.
+#[cfg(feature = "std")]
+fn ascii85_group(digits: &[u8; 5]) -> Option<[u8; 4]> {
+    let value = digits
+        .iter()
+        .try_fold(0u32, |value, &digit| value.checked_mul(85)?.checked_add(digit as u32))?;
+    Some(value.to_be_bytes())
+}
+
+/// The label used for PEM blocks produced by `EncodingFormat::Pem`.
+#[cfg(feature = "std")]
+pub const DEFAULT_PEM_LABEL: &str = "GENRS KEY";
+
+/// Encodes the given key as an RFC 7468 PEM block with the given label, wrapping the Base64
+/// body at 64 characters per line. The result has no trailing newline.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::encode_pem;
+///
+/// let pem = encode_pem([0u8; 48], "GENRS KEY");
+/// assert_eq!(
+///     pem,
+///     "-----BEGIN GENRS KEY-----\n\
+///      AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\n\
+///      -----END GENRS KEY-----"
+/// );
+///
+/// let pem = encode_pem([0u8; 64], "SECRET");
+/// assert_eq!(pem.lines().count(), 4);
+/// assert!(pem.lines().all(|line| line.len() <= 64));
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_pem(key: impl AsRef<[u8]>, label: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(key);
+    let mut pem = String::with_capacity(encoded.len() + encoded.len() / 64 + 2 * label.len() + 32);
+    pem.push_str("-----BEGIN ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    for line in encoded.as_bytes().chunks(64) {
+        // Base64 output is pure ASCII, so every chunk is valid UTF-8.
+        pem.push_str(std::str::from_utf8(line).expect("Base64 output is ASCII"));
+        pem.push('\n');
+    }
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----");
+    wipe(encoded);
+    pem
+}
+
+/// Decodes an RFC 7468 PEM block, returning its label and the decoded bytes.
+///
+/// Following the lax parsing rules of RFC 7468, leading and trailing whitespace, CRLF line
+/// endings and any line length in the body are accepted. The `END` label must match the
+/// `BEGIN` label.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{decode_pem, encode_pem};
+///
+/// let pem = encode_pem(b"foobar", "TEST KEY");
+/// assert_eq!(decode_pem(&pem).unwrap(), ("TEST KEY".to_string(), b"foobar".to_vec()));
+///
+/// let lax = "\r\n-----BEGIN TEST KEY-----\r\nZm9v\r\nYmFy\r\n-----END TEST KEY-----\r\n";
+/// assert_eq!(decode_pem(lax).unwrap().1, b"foobar");
+///
+/// assert!(decode_pem("-----BEGIN A-----\nZm9vYmFy\n-----END B-----").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidEncoding` if the encapsulation boundaries are missing or
+/// mismatched, or if the body is not valid Base64.
+#[cfg(feature = "std")]
+pub fn decode_pem(encoded: &str) -> Result<(String, Vec<u8>), GenrsError> {
+    let invalid = |reason: &str| GenrsError::InvalidEncoding(format!("Invalid PEM input: {}", reason));
+
+    let rest = encoded.trim().strip_prefix("-----BEGIN ").ok_or_else(|| invalid("missing BEGIN line"))?;
+    let (label, rest) = rest.split_once("-----").ok_or_else(|| invalid("malformed BEGIN line"))?;
+    let end = format!("-----END {}-----", label);
+    let body = rest.strip_suffix(end.as_str()).ok_or_else(|| invalid("missing or mismatched END line"))?;
+
+    let body: String = body.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(&body)
+        .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid PEM input: {}", err)));
+    wipe(body);
+    Ok((label.to_string(), decoded?))
+}
+
+/// The human-readable part used for bech32 strings produced by `EncodingFormat::Bech32`.
+#[cfg(feature = "std")]
+pub const DEFAULT_BECH32_HRP: &str = "genrs";
+
+/// The maximum length of a bech32 string, including the human-readable part, the `1` separator
+/// and the checksum (BIP 173).
+#[cfg(feature = "std")]
+const BECH32_MAX_LENGTH: usize = 90;
+
+/// Returns the longest key, in bytes, that fits into a bech32 string with a human-readable part
+/// of `hrp_len` characters.
+#[cfg(feature = "std")]
+fn bech32_max_key_length(hrp_len: usize) -> usize {
+    // Every 5 bits of data take one character, next to the separator and 6 checksum characters.
+    BECH32_MAX_LENGTH.saturating_sub(hrp_len + 1 + 6) * 5 / 8
+}
+
+/// Encodes the given key as a BIP 173 bech32 string with the given human-readable part, as used
+/// for Lightning and Cosmos-style identifiers.
+///
+/// The key bytes are regrouped into 5-bit symbols and followed by a 6-character checksum over the
+/// human-readable part and the data. The whole string is limited to 90 characters, so with the
+/// default human-readable part `genrs` keys can be at most 48 bytes long.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::encode_bech32;
+///
+/// // Test vector from BIP 173.
+/// let data = [
+///     0x00, 0x44, 0x32, 0x14, 0xc7, 0x42, 0x54, 0xb6, 0x35, 0xcf, 0x84, 0x65, 0x3a, 0x56, 0xd7, 0xc6, 0x75, 0xbe,
+///     0x77, 0xdf,
+/// ];
+/// assert_eq!(encode_bech32(data, "abcdef").unwrap(), "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw");
+///
+/// assert!(encode_bech32(data, "ABCDEF").is_err());
+/// assert!(encode_bech32(data, "").is_err());
+/// assert!(encode_bech32([0u8; 49], "genrs").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidHrp` if the human-readable part is empty, longer than 83
+/// characters, or contains characters other than lowercase printable ASCII, and
+/// `GenrsError::InvalidLength` if the key does not fit into a 90-character bech32 string.
+#[cfg(feature = "std")]
+pub fn encode_bech32(key: impl AsRef<[u8]>, hrp: &str) -> Result<String, GenrsError> {
+    let key = key.as_ref();
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(GenrsError::InvalidHrp(format!("must be 1 to 83 characters long, got {}", hrp.len())));
+    }
+    if let Some(c) = hrp.chars().find(|c| !matches!(c, '!'..='~') || c.is_ascii_uppercase()) {
+        return Err(GenrsError::InvalidHrp(format!(
+            "'{}' is not allowed, only lowercase printable ASCII characters are",
+            c
+        )));
+    }
+    let max_len = bech32_max_key_length(hrp.len());
+    if key.len() > max_len {
+        return Err(GenrsError::InvalidLength(format!(
+            "a bech32 string with the human-readable part '{}' can encode at most {} bytes, got {}",
+            hrp,
+            max_len,
+            key.len()
+        )));
+    }
+
+    let hrp = bech32::Hrp::parse(hrp).map_err(|err| GenrsError::InvalidHrp(err.to_string()))?;
+    bech32::encode::<bech32::Bech32>(hrp, key).map_err(|err| GenrsError::InvalidEncoding(err.to_string()))
+}
+
+/// Decodes a BIP 173 bech32 string, returning its (lowercase) human-readable part and the decoded
+/// bytes.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{decode_bech32, encode_bech32};
+///
+/// let encoded = encode_bech32(b"foobar", "test").unwrap();
+/// assert_eq!(decode_bech32(&encoded).unwrap(), ("test".to_string(), b"foobar".to_vec()));
+/// assert_eq!(decode_bech32(&encoded.to_uppercase()).unwrap().1, b"foobar");
+///
+/// // A single changed character invalidates the checksum.
+/// assert!(decode_bech32("genrs1vehk7cnpwgxsafmq").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidEncoding` if the input is not a valid bech32 string, e.g. because
+/// it mixes upper and lowercase characters or its checksum does not match.
+#[cfg(feature = "std")]
+pub fn decode_bech32(encoded: &str) -> Result<(String, Vec<u8>), GenrsError> {
+    let checked = bech32::primitives::decode::CheckedHrpstring::new::<bech32::Bech32>(encoded.trim())
+        .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid bech32 input: {}", err)))?;
+    Ok((checked.hrp().to_lowercase(), checked.byte_iter().collect()))
+}
+
+/// Encodes a single key into each of the given formats, in order.
+///
+/// This guarantees that all encodings describe the same key bytes, e.g. when recording both the
+/// hex and Base64 forms of a freshly provisioned key.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_key_formats, EncodingFormat};
+///
+/// let encoded = encode_key_formats([0xfb_u8, 0xff], &[EncodingFormat::Hex, EncodingFormat::Base64]).unwrap();
+/// assert_eq!(encoded, vec!["fbff".to_string(), "+/8=".to_string()]);
+/// ```
+///
+/// # Errors
+///
+/// Returns the first error produced by `encode_key`.
+#[cfg(feature = "std")]
+pub fn encode_key_formats(key: impl AsRef<[u8]>, formats: &[EncodingFormat]) -> Result<Vec<String>, GenrsError> {
+    let key = key.as_ref();
+    formats.iter().map(|&format| encode_key(key, format)).collect()
+}
+
+/// Encodes the given key into the specified format with uppercase output.
+///
+/// Only case-insensitive formats (see `EncodingFormat::is_case_insensitive`) can be uppercased:
+/// hex switches to `A-F` and bech32 to an all-uppercase string (e.g. for QR codes), while the
+/// Base32 alphabets are uppercase already.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_key_uppercase, EncodingFormat};
+///
+/// assert_eq!(encode_key_uppercase([0xde_u8, 0xad, 0xbe, 0xef], EncodingFormat::Hex).unwrap(), "DEADBEEF");
+/// assert_eq!(encode_key_uppercase(b"foobar", EncodingFormat::Base32).unwrap(), "MZXW6YTBOI");
+/// assert_eq!(encode_key_uppercase(b"foobar", EncodingFormat::Bech32).unwrap(), "GENRS1VEHK7CNPWGXSAFMH");
+/// assert!(encode_key_uppercase(b"foobar", EncodingFormat::Base64).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::UnsupportedFormat` if the format is case-sensitive, such as Base64 or Base58.
+#[cfg(feature = "std")]
+pub fn encode_key_uppercase(key: impl AsRef<[u8]>, format: EncodingFormat) -> Result<String, GenrsError> {
+    match format {
+        EncodingFormat::Hex => Ok(encode_hex_ct(key, HexConfig { uppercase: true, prefix: false })),
+        _ if format.is_case_insensitive() => encode_key(key, format).map(|mut encoded| {
+            encoded.make_ascii_uppercase();
+            encoded
+        }),
+        _ => Err(GenrsError::UnsupportedFormat(format!(
+            "{} is case-sensitive and cannot be uppercased",
+            format
+        ))),
+    }
+}
+
+/// A Base64 codec with a caller-supplied alphabet, for systems that use a non-standard Base64
+/// variant such as bcrypt's `./A-Za-z0-9`.
+///
+/// The alphabet is validated and the engine built once in `CustomBase64::new`, so a single value
+/// can be reused to encode and decode many keys.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::CustomBase64;
+///
+/// let bcrypt = CustomBase64::new("./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", false).unwrap();
+/// let encoded = bcrypt.encode([0xfb_u8, 0xff]);
+/// assert_eq!(encoded, "896");
+/// assert_eq!(bcrypt.decode(&encoded).unwrap(), [0xfb, 0xff]);
+///
+/// // Alphabets must have 64 distinct printable ASCII characters other than `=`.
+/// assert!(CustomBase64::new("ABC", true).is_err());
+/// assert!(CustomBase64::new(&"A".repeat(64), true).is_err());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct CustomBase64 {
+    engine: base64::engine::GeneralPurpose,
+}
+
+#[cfg(feature = "std")]
+impl CustomBase64 {
+    /// Creates a codec for the given 64-character alphabet, with or without `=` padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GenrsError::InvalidAlphabet` if the alphabet does not consist of exactly 64
+    /// distinct printable ASCII characters, or if it contains the padding character `=`.
+    pub fn new(alphabet: &str, padded: bool) -> Result<Self, GenrsError> {
+        let alphabet =
+            base64::alphabet::Alphabet::new(alphabet).map_err(|err| GenrsError::InvalidAlphabet(err.to_string()))?;
+        let config = base64::engine::GeneralPurposeConfig::new()
+            .with_encode_padding(padded)
+            .with_decode_padding_mode(match padded {
+                true => base64::engine::DecodePaddingMode::RequireCanonical,
+                false => base64::engine::DecodePaddingMode::RequireNone,
+            });
+        Ok(CustomBase64 {
+            engine: base64::engine::GeneralPurpose::new(&alphabet, config),
+        })
+    }
+
+    /// Encodes the given key with the custom alphabet.
+    pub fn encode(&self, key: impl AsRef<[u8]>) -> String {
+        self.engine.encode(key)
+    }
+
+    /// Decodes a string produced with the custom alphabet.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GenrsError::InvalidEncoding` if the input contains characters outside the
+    /// alphabet or has invalid padding.
+    pub fn decode(&self, encoded: &str) -> Result<Vec<u8>, GenrsError> {
+        self.engine
+            .decode(encoded)
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid custom Base64 input: {}", err)))
+    }
+}
+
+/// Number of input bytes encoded per chunk by `encode_key_to_writer`. It is a multiple of 3
+/// (Base64), 4 (Ascii85) and 5 (Base32), so chunk boundaries never fall inside an encoding group.
+#[cfg(feature = "std")]
+const STREAM_CHUNK_SIZE: usize = 960;
+
+/// Checks that a key of `len` bytes can be encoded in the given format.
+///
+/// `encode_key` calls this before encoding, so formats that impose length limits report a
+/// descriptive error instead of producing malformed output. Callers can also use it to reject a
+/// length up front, before generating any key material. Only `Bech32` currently limits the key
+/// length, to 48 bytes with the default human-readable part.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{validate_for_format, EncodingFormat};
+///
+/// for format in EncodingFormat::ALL {
+///     assert!(validate_for_format(32, format).is_ok());
+/// }
+/// assert!(validate_for_format(49, EncodingFormat::Bech32).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if the format cannot represent a key of `len` bytes.
+#[cfg(feature = "std")]
+pub fn validate_for_format(len: usize, format: EncodingFormat) -> Result<(), GenrsError> {
+    // List every format explicitly, so that adding one forces a decision about its limits.
+    let max_len = match format {
+        EncodingFormat::Hex
+        | EncodingFormat::Base64
+        | EncodingFormat::Base64NoPad
+        | EncodingFormat::Base64Url
+        | EncodingFormat::Base64UrlNoPad
+        | EncodingFormat::Base32
+        | EncodingFormat::Base32Crockford { .. }
+        | EncodingFormat::Base58
+        | EncodingFormat::Ascii85
+        | EncodingFormat::Pem => None,
+        EncodingFormat::Bech32 => Some(bech32_max_key_length(DEFAULT_BECH32_HRP.len())),
+    };
+    match max_len {
+        Some(max_len) if len > max_len => Err(GenrsError::InvalidLength(format!(
+            "{} can encode at most {} bytes, got {}",
+            format, max_len, len
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Encodes the given key into the specified format and writes it to `out` chunk by chunk,
+/// producing exactly the same output as `encode_key` without building the whole string in memory.
+///
+/// `Base58` and `Bech32` cannot be encoded incrementally (every Base58 symbol and the bech32
+/// checksum depend on the whole input), so they are still encoded in one piece before being written.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_key, encode_key_to_writer, generate_key, EncodingFormat};
+///
+/// let key = generate_key(4096);
+/// for format in [
+///     EncodingFormat::Hex,
+///     EncodingFormat::Base64,
+///     EncodingFormat::Base64UrlNoPad,
+///     EncodingFormat::Base32,
+///     EncodingFormat::Base32Crockford { checksum: true },
+///     EncodingFormat::Ascii85,
+///     EncodingFormat::Pem,
+/// ] {
+///     let mut out = Vec::new();
+///     encode_key_to_writer(&key, format, &mut out).unwrap();
+///     assert_eq!(String::from_utf8(out).unwrap(), encode_key(&key, format).unwrap());
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns any error produced by the underlying writer.
+#[cfg(feature = "std")]
+pub fn encode_key_to_writer<W: Write>(key: impl AsRef<[u8]>, format: EncodingFormat, out: &mut W) -> io::Result<()> {
+    let key = key.as_ref();
+    let encode = |chunk: &[u8], format: EncodingFormat| encode_key(chunk, format).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err));
+    let write = |out: &mut W, encoded: String| {
+        let written = out.write_all(encoded.as_bytes());
+        wipe(encoded);
+        written
+    };
+    // Chunks are validated individually by `encode_key`, so check the length of the whole key here.
+    validate_for_format(key.len(), format).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+    match format {
+        EncodingFormat::Base58 | EncodingFormat::Bech32 => write(out, encode(key, format)?),
+        EncodingFormat::Pem => {
+            writeln!(out, "-----BEGIN {}-----", DEFAULT_PEM_LABEL)?;
+            // 48 bytes encode to exactly one 64-character line of Base64.
+            for chunk in key.chunks(48) {
+                write(out, encode(chunk, EncodingFormat::Base64)?)?;
+                out.write_all(b"\n")?;
+            }
+            write!(out, "-----END {}-----", DEFAULT_PEM_LABEL)
+        }
+        EncodingFormat::Base32Crockford { checksum } => {
+            let mut remainder = 0;
+            for chunk in key.chunks(STREAM_CHUNK_SIZE) {
+                let encoded = encode(chunk, EncodingFormat::Base32Crockford { checksum: false })?;
+                remainder = crockford_remainder(remainder, &encoded);
+                write(out, encoded)?;
+            }
+            if checksum {
+                out.write_all(&[CROCKFORD_SYMBOLS[remainder]])?;
+            }
+            Ok(())
+        }
+        _ => {
+            for chunk in key.chunks(STREAM_CHUNK_SIZE) {
+                write(out, encode(chunk, format)?)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Options for hexadecimal encoding with `encode_hex`.
+///
+/// The default produces lowercase output without a prefix, identical to `EncodingFormat::Hex`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HexConfig {
+    /// Use uppercase digits (`A-F`) instead of lowercase ones.
+    pub uppercase: bool,
+    /// Prefix the output with `0x`.
+    pub prefix: bool,
+}
+
+/// Encodes the given key as hexadecimal according to the given `HexConfig`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_hex, HexConfig};
+///
+/// let key = [0xde_u8, 0xad, 0xbe, 0xef];
+/// assert_eq!(encode_hex(key, HexConfig::default()), "deadbeef");
+/// assert_eq!(encode_hex(key, HexConfig { uppercase: true, prefix: false }), "DEADBEEF");
+/// assert_eq!(encode_hex(key, HexConfig { uppercase: false, prefix: true }), "0xdeadbeef");
+/// assert_eq!(encode_hex(key, HexConfig { uppercase: true, prefix: true }), "0xDEADBEEF");
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_hex(key: impl AsRef<[u8]>, config: HexConfig) -> String {
+    let digits = if config.uppercase {
+        hex::encode_upper(key)
+    } else {
+        hex::encode(key)
+    };
+
+    if config.prefix {
+        format!("0x{}", digits)
+    } else {
+        digits
+    }
+}
+
+/// Encodes the given key as hexadecimal in constant time, according to the given `HexConfig`.
+///
+/// The output is identical to `encode_hex`, but every nibble is converted with branch-free
+/// arithmetic instead of a table lookup, so neither the control flow nor the memory access
+/// pattern depends on the secret bytes. Prefer this function when encoding secrets in an
+/// environment where an attacker can observe timing.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_hex, encode_hex_ct, generate_key, HexConfig};
+///
+/// let key = [0x00_u8, 0x09, 0x0a, 0x9f, 0xf0, 0xff];
+/// assert_eq!(encode_hex_ct(key, HexConfig::default()), "00090a9ff0ff");
+/// assert_eq!(encode_hex_ct(key, HexConfig { uppercase: true, prefix: true }), "0x00090A9FF0FF");
+///
+/// let key = generate_key(32);
+/// let config = HexConfig { uppercase: true, prefix: false };
+/// assert_eq!(encode_hex_ct(&key, config), encode_hex(&key, config));
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_hex_ct(key: impl AsRef<[u8]>, config: HexConfig) -> String {
+    // Distance from '9' + 1 to the first letter: 39 for 'a', 7 for 'A'.
+    let letter_offset: i16 = if config.uppercase { 7 } else { 39 };
+    let key = key.as_ref();
+
+    let mut encoded = String::with_capacity(key.len() * 2 + 2);
+    if config.prefix {
+        encoded.push_str("0x");
+    }
+    for &byte in key {
+        for nibble in [byte >> 4, byte & 0x0f] {
+            let nibble = nibble as i16;
+            // `(9 - nibble) >> 8` is all ones for nibbles 10..=15 and zero otherwise.
+            let digit = b'0' as i16 + nibble + (((9 - nibble) >> 8) & letter_offset);
+            encoded.push(digit as u8 as char);
+        }
+    }
+    encoded
+}
+
+/// Formats the given bytes as lowercase hexadecimal, inserting `separator` after every
+/// `group_size` bytes (a `group_size` of 0 is treated as 1).
+///
+/// This is useful for displaying fingerprints or MAC-style values for humans to compare.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::format_hex;
+///
+/// let bytes = [0xde_u8, 0xad, 0xbe, 0xef];
+/// assert_eq!(format_hex(bytes, ":", 1), "de:ad:be:ef");
+/// assert_eq!(format_hex(bytes, " ", 2), "dead beef");
+/// assert_eq!(format_hex(bytes, "", 1), "deadbeef");
+/// ```
+#[cfg(feature = "std")]
+pub fn format_hex(bytes: impl AsRef<[u8]>, separator: &str, group_size: usize) -> String {
+    bytes
+        .as_ref()
+        .chunks(group_size.max(1))
+        .map(hex::encode)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Options for formatting keys as array literals with `encode_array_literal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayConfig {
+    /// Use uppercase hex digits (`0xDE`) instead of lowercase ones (`0xde`).
+    pub uppercase: bool,
+    /// Wrap the byte list in the language's array syntax, e.g. `{` and `}` for C.
+    pub brackets: bool,
+    /// Break the line after this many bytes and indent the byte lines by four spaces; `0` keeps
+    /// all bytes on one line.
+    pub bytes_per_line: usize,
+}
+
+impl Default for ArrayConfig {
+    /// A one-line, lowercase list in brackets, e.g. `{0xde, 0xad, 0xbe, 0xef}` for C.
+    fn default() -> Self {
+        ArrayConfig {
+            uppercase: false,
+            brackets: true,
+            bytes_per_line: 0,
+        }
+    }
+}
+
+/// Enum to represent the languages whose array literal syntax `encode_array_literal` supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayLanguage {
+    /// A C array initializer, `{0xde, 0xad}`.
+    C,
+    /// A Python `bytes` object, `bytes([0xde, 0xad])`.
+    Python,
+    /// A JavaScript `Uint8Array`, `Uint8Array.from([0xde, 0xad])`.
+    JavaScript,
+}
+
+impl ArrayLanguage {
+    /// Returns the text before and after the byte list.
+    #[cfg(feature = "std")]
+    fn brackets(&self) -> (&'static str, &'static str) {
+        match self {
+            ArrayLanguage::C => ("{", "}"),
+            ArrayLanguage::Python => ("bytes([", "])"),
+            ArrayLanguage::JavaScript => ("Uint8Array.from([", "])"),
+        }
+    }
+}
+
+impl fmt::Display for ArrayLanguage {
+    /// Formats the language as the lowercase name used by the CLI, e.g. `python`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ArrayLanguage::C => "c",
+            ArrayLanguage::Python => "python",
+            ArrayLanguage::JavaScript => "js",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ArrayLanguage {
+    type Err = GenrsError;
+
+    /// Parses the lowercase names used by the CLI: `c`, `python` or `js` (also `javascript`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::ArrayLanguage;
+    ///
+    /// assert_eq!("python".parse::<ArrayLanguage>().unwrap(), ArrayLanguage::Python);
+    /// assert_eq!("JS".parse::<ArrayLanguage>().unwrap(), ArrayLanguage::JavaScript);
+    /// assert!("rust".parse::<ArrayLanguage>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "c" => Ok(ArrayLanguage::C),
+            "python" => Ok(ArrayLanguage::Python),
+            "js" | "javascript" => Ok(ArrayLanguage::JavaScript),
+            _ => Err(GenrsError::UnsupportedFormat(s.to_string())),
+        }
+    }
+}
+
+/// Formats the given key as an array literal of the given language: a comma-separated list of
+/// `0x`-prefixed hex bytes in the language's array syntax, ready to be pasted into source code.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_array_literal, ArrayConfig, ArrayLanguage};
+///
+/// let key = [0xde_u8, 0xad, 0xbe, 0xef];
+/// let config = ArrayConfig::default();
+/// assert_eq!(encode_array_literal(key, ArrayLanguage::C, config), "{0xde, 0xad, 0xbe, 0xef}");
+/// assert_eq!(encode_array_literal(key, ArrayLanguage::Python, config), "bytes([0xde, 0xad, 0xbe, 0xef])");
+/// assert_eq!(
+///     encode_array_literal(key, ArrayLanguage::JavaScript, config),
+///     "Uint8Array.from([0xde, 0xad, 0xbe, 0xef])"
+/// );
+///
+/// let config = ArrayConfig { uppercase: true, brackets: false, bytes_per_line: 0 };
+/// assert_eq!(encode_array_literal(key, ArrayLanguage::Python, config), "0xDE, 0xAD, 0xBE, 0xEF");
+///
+/// let config = ArrayConfig { bytes_per_line: 3, ..Default::default() };
+/// assert_eq!(encode_array_literal(key, ArrayLanguage::C, config), "{\n    0xde, 0xad, 0xbe,\n    0xef\n}");
+/// assert_eq!(
+///     encode_array_literal(key, ArrayLanguage::JavaScript, config),
+///     "Uint8Array.from([\n    0xde, 0xad, 0xbe,\n    0xef\n])"
+/// );
+///
+/// let config = ArrayConfig { brackets: false, bytes_per_line: 2, ..Default::default() };
+/// assert_eq!(encode_array_literal(key, ArrayLanguage::C, config), "0xde, 0xad,\n0xbe, 0xef");
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_array_literal(key: impl AsRef<[u8]>, language: ArrayLanguage, config: ArrayConfig) -> String {
+    let byte_list = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .map(|byte| match config.uppercase {
+                true => format!("0x{:02X}", byte),
+                false => format!("0x{:02x}", byte),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let key = key.as_ref();
+    let (open, close) = language.brackets();
+
+    match (config.bytes_per_line, config.brackets) {
+        (0, true) => format!("{}{}{}", open, byte_list(key), close),
+        (0, false) => byte_list(key),
+        (bytes_per_line, brackets) => {
+            let indent = if brackets { "    " } else { "" };
+            let lines: Vec<String> =
+                key.chunks(bytes_per_line).map(|line| format!("{}{}", indent, byte_list(line))).collect();
+            match brackets {
+                true => format!("{}\n{}\n{}", open, lines.join(",\n"), close),
+                false => lines.join(",\n"),
+            }
+        }
+    }
+}
+
+/// Formats the given key as a C array initializer, e.g. `{0xde, 0xad, 0xbe, 0xef}`, ready to be
+/// pasted into firmware source. Equivalent to `encode_array_literal` with `ArrayLanguage::C`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_c_array, ArrayConfig};
+///
+/// let key = [0xde_u8, 0xad, 0xbe, 0xef];
+/// assert_eq!(encode_c_array(key, ArrayConfig::default()), "{0xde, 0xad, 0xbe, 0xef}");
+///
+/// let config = ArrayConfig { uppercase: true, brackets: false, bytes_per_line: 0 };
+/// assert_eq!(encode_c_array(key, config), "0xDE, 0xAD, 0xBE, 0xEF");
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_c_array(key: impl AsRef<[u8]>, config: ArrayConfig) -> String {
+    encode_array_literal(key, ArrayLanguage::C, config)
+}
+
+/// Wraps an encoded value in an optional prefix and suffix, e.g. to make API keys recognizable
+/// in logs and secret scanners.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::decorate;
+///
+/// assert_eq!(decorate("abc123", Some("sk_live_"), None), "sk_live_abc123");
+/// assert_eq!(decorate("abc123", Some("<"), Some(">")), "<abc123>");
+/// assert_eq!(decorate("abc123", None, None), "abc123");
+/// ```
+#[cfg(feature = "std")]
+pub fn decorate(value: &str, prefix: Option<&str>, suffix: Option<&str>) -> String {
+    let prefix = prefix.unwrap_or_default();
+    let suffix = suffix.unwrap_or_default();
+    let mut decorated = String::with_capacity(prefix.len() + value.len() + suffix.len());
+    decorated.push_str(prefix);
+    decorated.push_str(value);
+    decorated.push_str(suffix);
+    decorated
+}
+
+/// Quotes a value for POSIX shells, so that `eval` or `source` assign it verbatim.
+///
+/// The value is wrapped in single quotes, inside which the shell interprets nothing. A single
+/// quote in the value ends the quoted string, is added as an escaped `\'` and reopens it.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::shell_quote;
+///
+/// assert_eq!(shell_quote("abc123"), "'abc123'");
+/// assert_eq!(shell_quote(""), "''");
+/// assert_eq!(shell_quote("it's"), r"'it'\''s'");
+/// assert_eq!(shell_quote("$HOME `id` \"x\" \\ !"), r#"'$HOME `id` "x" \ !'"#);
+/// assert_eq!(shell_quote("a\nb"), "'a\nb'");
+/// ```
+#[cfg(feature = "std")]
+pub fn shell_quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('\'');
+    for c in value.chars() {
+        match c {
+            '\'' => quoted.push_str("'\\''"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Quotes a value for a `.env` file line `NAME=value`.
+///
+/// Values consisting only of letters, digits and `_-.+/=:@,~` (e.g. hex, Base64 and Base58 keys)
+/// are left unquoted. Other values are single-quoted, which dotenv loaders read literally, without
+/// variable expansion. Values containing a single quote or a line break are double-quoted instead,
+/// with `\`, `"`, `$` and `` ` `` escaped by a backslash and line breaks written as `\n`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::dotenv_quote;
+///
+/// assert_eq!(dotenv_quote("q83vEjRWeJA+/w=="), "q83vEjRWeJA+/w==");
+/// assert_eq!(dotenv_quote("correct horse #1"), "'correct horse #1'");
+/// assert_eq!(dotenv_quote("$HOME"), "'$HOME'");
+/// assert_eq!(dotenv_quote(""), "''");
+/// assert_eq!(dotenv_quote("it's $5"), r#""it's \$5""#);
+/// assert_eq!(dotenv_quote("a\"b\\c\nd"), r#""a\"b\\c\nd""#);
+/// ```
+#[cfg(feature = "std")]
+pub fn dotenv_quote(value: &str) -> String {
+    let is_plain = |c: char| c.is_ascii_alphanumeric() || "_-.+/=:@,~".contains(c);
+    if !value.is_empty() && value.chars().all(is_plain) {
+        return value.to_string();
+    }
+    if !value.contains(['\'', '\n', '\r']) {
+        return format!("'{}'", value);
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' | '"' | '$' | '`' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Returns a short fingerprint of the given key: the first 8 bytes of its SHA-256 digest, as hex.
+///
+/// The fingerprint lets recipients verify that they received the correct key without the key
+/// itself being sent over a second channel.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::key_fingerprint;
+///
+/// assert_eq!(key_fingerprint(b"abc"), "ba7816bf8f01cfea");
+/// ```
+#[cfg(feature = "std")]
+pub fn key_fingerprint(key: &[u8]) -> String {
+    hex::encode(&Sha256::digest(key)[..8])
+}
+
+/// Generates a random key of the given length in bytes, wrapped in `Zeroizing` so that the key
+/// material is cleared from memory when it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_key_zeroizing;
+///
+/// let key = generate_key_zeroizing(32);
+/// assert_eq!(key.len(), 32);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "zeroize")]
+pub fn generate_key_zeroizing(length: usize) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(generate_key(length))
+}
+
+/// Encodes the given key like `encode_key`, wrapping the result in `Zeroizing` so that the
+/// encoded key is cleared from memory when it is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{encode_key_zeroizing, generate_key_zeroizing, EncodingFormat};
+///
+/// let key = generate_key_zeroizing(32);
+/// let encoded_key = encode_key_zeroizing(&*key, EncodingFormat::Hex).unwrap();
+/// assert_eq!(encoded_key.len(), 64);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as `encode_key`.
+#[cfg(feature = "zeroize")]
+pub fn encode_key_zeroizing(key: impl AsRef<[u8]>, format: EncodingFormat) -> Result<Zeroizing<String>, GenrsError> {
+    encode_key(key, format).map(Zeroizing::new)
+}
+
+/// Clears an intermediate buffer holding encoded key material before it is dropped.
+#[cfg(feature = "zeroize")]
+fn wipe(mut value: String) {
+    value.zeroize();
+}
+
+/// Drops an intermediate buffer holding encoded key material (zeroized with the `zeroize` feature).
+#[cfg(all(feature = "std", not(feature = "zeroize")))]
+fn wipe(_value: String) {}
+
+/// Crockford's Base32 symbols, followed by the five extra check symbols `*~$=U`.
+#[cfg(feature = "std")]
+const CROCKFORD_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Computes the Crockford check symbol for an encoded string: the encoded value modulo 37.
+#[cfg(feature = "std")]
+fn crockford_checksum(encoded: &str) -> char {
+    CROCKFORD_SYMBOLS[crockford_remainder(0, encoded)] as char
+}
+
+/// Continues the modulo 37 computation of a Crockford check symbol over more encoded symbols.
+#[cfg(feature = "std")]
+fn crockford_remainder(remainder: usize, encoded: &str) -> usize {
+    encoded
+        .bytes()
+        .filter_map(|symbol| CROCKFORD_SYMBOLS[..32].iter().position(|&s| s == symbol))
+        .fold(remainder, |acc, value| (acc * 32 + value) % 37)
+}
+
+/// Describes the first of the `(position, symbol)` pairs that `base32::decode` rejects, without
+/// repeating the rest of the input, which is usually secret key material.
+#[cfg(feature = "std")]
+fn invalid_base32_symbol(symbols: impl IntoIterator<Item = (usize, char)>, alphabet: base32::Alphabet) -> String {
+    symbols
+        .into_iter()
+        .find(|(_, symbol)| base32::decode(alphabet, symbol.encode_utf8(&mut [0; 4])).is_none())
+        .map_or_else(
+            || "invalid symbol".to_string(),
+            |(position, symbol)| format!("invalid symbol '{}' at position {}", symbol, position + 1),
+        )
+}
+
+/// Decodes a Crockford Base32 string back into raw bytes.
+///
+/// Decoding is case-insensitive, ignores `-` separators and accepts the `I`/`L` and `O`
+/// aliases for `1` and `0`. When `checksum` is set, the last symbol is treated as a check
+/// symbol (which may be one of `*~$=U`) and verified against the decoded data.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::decode_crockford;
+///
+/// assert_eq!(decode_crockford("csqpyrk1e8r", true).unwrap(), b"foobar");
+/// assert_eq!(decode_crockford("CSQP-YRKI-E8", false).unwrap(), b"foobar");
+/// assert!(decode_crockford("CSQPYRK1E8S", true).is_err());
+///
+/// let err = decode_crockford("CSQP-YRKU-E8", false).unwrap_err();
+/// assert_eq!(err.to_string(), "Invalid Crockford Base32 input: invalid symbol 'U' at position 9");
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidEncoding` if the input contains invalid symbols or the check
+/// symbol does not match.
+#[cfg(feature = "std")]
+pub fn decode_crockford(encoded: &str, checksum: bool) -> Result<Vec<u8>, GenrsError> {
+    let mut normalized: String = encoded
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        })
+        .collect();
+
+    let check = if checksum {
+        Some(normalized
+            .pop()
+            .ok_or_else(|| GenrsError::InvalidEncoding("Missing Crockford Base32 check symbol".to_string()))?)
+    } else {
+        None
+    };
+
+    let decoded = base32::decode(base32::Alphabet::Crockford, &normalized).ok_or_else(|| {
+        let mut symbols: Vec<(usize, char)> = encoded.chars().enumerate().filter(|(_, c)| *c != '-').collect();
+        if checksum {
+            symbols.pop();
+        }
+        GenrsError::InvalidEncoding(format!(
+            "Invalid Crockford Base32 input: {}",
+            invalid_base32_symbol(symbols, base32::Alphabet::Crockford)
+        ))
+    })?;
+
+    if let Some(check) = check {
+        let expected = crockford_checksum(&normalized);
+        if check != expected {
+            return Err(GenrsError::InvalidEncoding(format!(
+                "Crockford Base32 checksum mismatch: expected '{}', found '{}'",
+                expected, check
+            )));
+        }
+    }
+
+    Ok(decoded)
+}
+
+/// Decodes the given string from the specified format back into the raw key bytes.
+///
+/// This is the inverse of `encode_key` and is useful for validating keys received from
+/// another system before storing them.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{decode_key, encode_key, generate_key, EncodingFormat};
+///
+/// let key = generate_key(32);
+/// let encoded_key = encode_key(&key, EncodingFormat::Base64).unwrap();
+/// assert_eq!(decode_key(&encoded_key, EncodingFormat::Base64).unwrap(), key);
+///
+/// assert_eq!(decode_key("deadbeef", EncodingFormat::Hex).unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+/// assert!(decode_key("not hex", EncodingFormat::Hex).is_err());
+///
+/// let key = [0u8, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0x01];
+/// let ascii85 = encode_key(key, EncodingFormat::Ascii85).unwrap();
+/// assert_eq!(ascii85, "zs8W-!!<");
+/// assert_eq!(decode_key(&ascii85, EncodingFormat::Ascii85).unwrap(), key);
+/// assert_eq!(decode_key("<~9jqo^~>", EncodingFormat::Ascii85).unwrap(), b"Man ");
+/// assert!(decode_key("s8W-\"", EncodingFormat::Ascii85).is_err());
+///
+/// // Errors name the offending symbol, never the (possibly secret) input itself.
+/// let err = decode_key("MZXW6YT1", EncodingFormat::Base32).unwrap_err();
+/// assert_eq!(err.to_string(), "Invalid Base32 input: invalid symbol '1' at position 8");
+/// ```
+///
+/// Every format round-trips: decoding the encoded form of a key returns the original bytes.
+/// A seeded RNG keeps the inputs reproducible, and lengths that are not a multiple of the
+/// encoding group size exercise the padding and partial-group handling.
+///
+/// ```
+/// use genrs_lib::{decode_key, encode_key, generate_key_with_rng, validate_for_format, EncodingFormat};
+/// use rand_chacha::rand_core::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+///
+/// let mut rng = ChaCha20Rng::seed_from_u64(0x6765_6e72_73);
+/// for format in EncodingFormat::ALL {
+///     for length in 1..=70 {
+///         if validate_for_format(length, format).is_err() {
+///             continue;
+///         }
+///         let key = generate_key_with_rng(length, &mut rng);
+///         let encoded = encode_key(&key, format).unwrap();
+///         assert_eq!(decode_key(&encoded, format).unwrap(), key, "{} round trip of {} bytes", format, length);
+///     }
+///
+///     // Leading zero bytes are significant for Base58 and collapse to `z` in Ascii85.
+///     let zeros = [0u8; 9];
+///     assert_eq!(decode_key(&encode_key(zeros, format).unwrap(), format).unwrap(), zeros, "{} zeros", format);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidEncoding` if the input contains characters outside the format's
+/// alphabet or has an invalid length for the format.
+#[cfg(feature = "std")]
+pub fn decode_key(encoded: &str, format: EncodingFormat) -> Result<Vec<u8>, GenrsError> {
+    match format {
+        EncodingFormat::Hex => hex::decode(encoded).map_err(|err| GenrsError::InvalidEncoding(format!("Invalid hex input: {}", err))),
+        EncodingFormat::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid Base64 input: {}", err))),
+        EncodingFormat::Base64NoPad => base64::engine::general_purpose::STANDARD_NO_PAD
+            .decode(encoded)
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid Base64 input: {}", err))),
+        EncodingFormat::Base64Url => base64::engine::general_purpose::URL_SAFE
+            .decode(encoded)
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid URL-safe Base64 input: {}", err))),
+        EncodingFormat::Base64UrlNoPad => base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid URL-safe Base64 input: {}", err))),
+        EncodingFormat::Base32 => {
+            let alphabet = base32::Alphabet::RFC4648 { padding: false };
+            let unpadded = encoded.trim_end_matches('=');
+            base32::decode(alphabet, unpadded).ok_or_else(|| {
+                let symbols = unpadded.chars().enumerate();
+                GenrsError::InvalidEncoding(format!("Invalid Base32 input: {}", invalid_base32_symbol(symbols, alphabet)))
+            })
+        }
+        EncodingFormat::Base32Crockford { checksum } => decode_crockford(encoded, checksum),
+        EncodingFormat::Base58 => bs58::decode(encoded)
+            .into_vec()
+            .map_err(|err| GenrsError::InvalidEncoding(format!("Invalid Base58 input: {}", err))),
+        EncodingFormat::Ascii85 => decode_ascii85(encoded),
+        EncodingFormat::Pem => decode_pem(encoded).map(|(_, decoded)| decoded),
+        EncodingFormat::Bech32 => decode_bech32(encoded).map(|(_, decoded)| decoded),
+    }
+}
+
+/// Guesses the encoding of a pasted key from its character set and length, so that the right
+/// decoder can be picked without asking the user.
+///
+/// The checks run from the most to the least distinctive format, and a candidate is only
+/// returned if `decode_key` actually accepts the input:
+///
+/// 1. `-----BEGIN` boundaries indicate `Pem`.
+/// 2. A string with a valid bech32 checksum is `Bech32`.
+/// 3. Only hex digits, with an even length, is `Hex`.
+/// 4. Only uppercase `A-Z` and `2-7` (optionally `=`-padded) is `Base32`.
+/// 5. `+` or `/` indicate standard Base64, `-` or `_` URL-safe Base64; the padding, or a length
+///    that is not a multiple of 4, picks the padded or unpadded variant.
+/// 6. Otherwise, an alphanumeric string that contains `0`, `O`, `I` or `l` (which Base58 leaves
+///    out) is standard Base64.
+///
+/// # Limitations
+///
+/// Many strings are valid in several encodings, and such strings are resolved in favor of the
+/// smaller alphabet: `deadbeef` is reported as hex, although it is valid Base64 as well, and an
+/// uppercase hex-like string such as `ABCD2345` is hex rather than Base32. Alphanumeric strings
+/// that are valid in both Base58 and Base64 are ambiguous and yield `None`. Crockford's Base32
+/// and Ascii85 overlap with the other formats too much to be detected reliably and are never
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{detect_encoding, encode_key, EncodingFormat};
+///
+/// assert_eq!(detect_encoding("deadbeef"), Some(EncodingFormat::Hex));
+/// assert_eq!(detect_encoding("MZXW6YTBOI"), Some(EncodingFormat::Base32));
+/// assert_eq!(detect_encoding("+/8="), Some(EncodingFormat::Base64));
+/// assert_eq!(detect_encoding("-_8"), Some(EncodingFormat::Base64UrlNoPad));
+/// assert_eq!(detect_encoding("genrs1vehk7cnpwgxsafmh"), Some(EncodingFormat::Bech32));
+///
+/// let pem = encode_key(b"foobar", EncodingFormat::Pem).unwrap();
+/// assert_eq!(detect_encoding(&pem), Some(EncodingFormat::Pem));
+///
+/// // Valid Base58 and valid Base64, so there is no way to tell.
+/// assert_eq!(detect_encoding("3mJr7AoUXx2Wqd"), None);
+/// assert_eq!(detect_encoding("not a key!"), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn detect_encoding(s: &str) -> Option<EncodingFormat> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let accepts = |format: EncodingFormat| decode_key(s, format).is_ok();
+
+    if s.starts_with("-----BEGIN ") {
+        return Some(EncodingFormat::Pem).filter(|&format| accepts(format));
+    }
+    if s.contains('1') && accepts(EncodingFormat::Bech32) {
+        return Some(EncodingFormat::Bech32);
+    }
+    if s.len().is_multiple_of(2) && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Some(EncodingFormat::Hex);
+    }
+
+    let body = s.trim_end_matches('=');
+    let padded = body.len() != s.len();
+    if body.bytes().all(|b| matches!(b, b'A'..=b'Z' | b'2'..=b'7')) && accepts(EncodingFormat::Base32) {
+        return Some(EncodingFormat::Base32);
+    }
+
+    let standard = body.contains(['+', '/']);
+    let url_safe = body.contains(['-', '_']);
+    let base64 = |padded_format: EncodingFormat, unpadded_format: EncodingFormat| {
+        let format = if padded || s.len().is_multiple_of(4) { padded_format } else { unpadded_format };
+        Some(format).filter(|&format| accepts(format))
+    };
+    match (standard, url_safe) {
+        (true, true) => None,
+        (true, false) => base64(EncodingFormat::Base64, EncodingFormat::Base64NoPad),
+        (false, true) => base64(EncodingFormat::Base64Url, EncodingFormat::Base64UrlNoPad),
+        // Base58 leaves out `0`, `O`, `I` and `l`, so any of them rules it out.
+        (false, false) if body.contains(['0', 'O', 'I', 'l']) => {
+            base64(EncodingFormat::Base64, EncodingFormat::Base64NoPad)
+        }
+        (false, false) => None,
+    }
+}
+
+/// Argon2id memory cost in KiB used by `derive_key` (19 MiB, the OWASP recommended minimum).
+#[cfg(feature = "kdf")]
+pub const ARGON2_MEMORY_KIB: u32 = 19 * 1024;
+/// Argon2id number of passes used by `derive_key`.
+#[cfg(feature = "kdf")]
+pub const ARGON2_ITERATIONS: u32 = 2;
+/// Argon2id degree of parallelism used by `derive_key`.
+#[cfg(feature = "kdf")]
+pub const ARGON2_PARALLELISM: u32 = 1;
+/// PBKDF2-HMAC-SHA256 iteration count used by `derive_key` (the OWASP 2023 recommendation).
+#[cfg(feature = "kdf")]
+pub const PBKDF2_ITERATIONS: u32 = 600_000;
+/// Minimum salt length in bytes accepted by `derive_key`.
+#[cfg(feature = "kdf")]
+pub const MIN_SALT_LENGTH: usize = 8;
+
+/// Enum to represent the key derivation function used by `derive_key`.
+///
+/// Both functions use fixed, conservative parameters so that a derived key can always be
+/// reproduced from the same passphrase and salt:
+///
+/// - `Argon2id`: `ARGON2_MEMORY_KIB` (19 MiB) of memory, `ARGON2_ITERATIONS` (2) passes and
+///   `ARGON2_PARALLELISM` (1) lane. Memory-hard, and the recommended choice.
+/// - `Pbkdf2Sha256`: PBKDF2-HMAC-SHA256 with `PBKDF2_ITERATIONS` (600,000) iterations, for
+///   interoperability with systems that do not support Argon2.
+#[cfg(feature = "kdf")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kdf {
+    #[default]
+    Argon2id,
+    Pbkdf2Sha256,
+}
+
+#[cfg(feature = "kdf")]
+impl fmt::Display for Kdf {
+    /// Formats the KDF as the lowercase name used by the CLI: `argon2id` or `pbkdf2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Kdf::Argon2id => "argon2id",
+            Kdf::Pbkdf2Sha256 => "pbkdf2",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(feature = "kdf")]
+impl FromStr for Kdf {
+    type Err = GenrsError;
+
+    /// Parses the lowercase names used by the CLI: `argon2id` or `pbkdf2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "argon2id" => Ok(Kdf::Argon2id),
+            "pbkdf2" => Ok(Kdf::Pbkdf2Sha256),
+            _ => Err(GenrsError::UnsupportedFormat(s.to_string())),
+        }
+    }
+}
+
+/// Derives a reproducible key of the given length in bytes from a passphrase and salt.
+///
+/// Unlike the other generators, the output is deterministic: the same passphrase, salt, length
+/// and `Kdf` always produce the same key. The security of the key therefore depends entirely
+/// on the strength of the passphrase; refer to `Kdf` for the parameters used.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{derive_key, Kdf};
+///
+/// let key = derive_key("correct horse battery staple", b"example salt", 32, Kdf::Argon2id).unwrap();
+/// assert_eq!(key.len(), 32);
+/// assert_eq!(key, derive_key("correct horse battery staple", b"example salt", 32, Kdf::Argon2id).unwrap());
+///
+/// assert!(derive_key("passphrase", b"short", 32, Kdf::Pbkdf2Sha256).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if the salt is shorter than `MIN_SALT_LENGTH` bytes, or
+/// if the length is not supported by the KDF (Argon2id requires at least 4 bytes).
+#[cfg(feature = "kdf")]
+pub fn derive_key(passphrase: &str, salt: &[u8], length: usize, kdf: Kdf) -> Result<Vec<u8>, GenrsError> {
+    if salt.len() < MIN_SALT_LENGTH {
+        return Err(GenrsError::InvalidLength(format!(
+            "the salt must be at least {} bytes, got {}",
+            MIN_SALT_LENGTH,
+            salt.len()
+        )));
+    }
+
+    let mut key = vec![0u8; length];
+    match kdf {
+        Kdf::Argon2id => {
+            let params = argon2::Params::new(ARGON2_MEMORY_KIB, ARGON2_ITERATIONS, ARGON2_PARALLELISM, Some(length))
+                .map_err(|err| GenrsError::InvalidLength(format!("unsupported Argon2id output length {}: {}", length, err)))?;
+            argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+                .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+                .map_err(|err| GenrsError::InvalidLength(format!("Argon2id derivation failed: {}", err)))?;
+        }
+        Kdf::Pbkdf2Sha256 => pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ITERATIONS, &mut key),
+    }
+    Ok(key)
+}
+
+/// Length in bytes of the secrets generated for TOTP (160 bits, as recommended by RFC 4226).
+#[cfg(feature = "std")]
+pub const TOTP_SECRET_LENGTH: usize = 20;
+
+/// Builds an `otpauth://totp/` provisioning URI for authenticator apps from a TOTP secret.
+///
+/// The secret is Base32-encoded without padding, and the issuer and account are
+/// percent-encoded, so that spaces, `:` or `@` in them do not corrupt the label.
+/// Generate the secret with `generate_key(TOTP_SECRET_LENGTH)`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::totp_uri;
+///
+/// let uri = totp_uri(b"12345678901234567890", "ACME Co", "alice@example.com");
+/// assert_eq!(
+///     uri,
+///     "otpauth://totp/ACME%20Co:alice%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=ACME%20Co"
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub fn totp_uri(secret: &[u8], issuer: &str, account: &str) -> String {
+    let issuer = percent_encode(issuer);
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}",
+        issuer,
+        percent_encode(account),
+        base32::encode(base32::Alphabet::RFC4648 { padding: false }, secret),
+        issuer
+    )
+}
+
+/// Percent-encodes every byte of `value` except the RFC 3986 unreserved characters.
+#[cfg(feature = "std")]
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Enum to represent the character set used for password-style keys.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use genrs_lib::{generate_password, Charset};
+///
+/// let password = generate_password(16, Charset::Alphanumeric);
+/// assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+/// # }
+/// ```
+///
+/// Refer to the `generate_password` function for usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    /// `A-Z`, `a-z` and `0-9` (62 symbols).
+    Alphanumeric,
+    /// `A-Z` and `a-z` (52 symbols).
+    Alphabetic,
+    /// All printable ASCII characters except space, `!` through `~` (94 symbols).
+    PrintableAscii,
+    /// `A-Z` and `0-9` (36 symbols), e.g. for license keys.
+    UppercaseAlphanumeric,
+    /// `A-Z` and `2-9` without the easily confused `I`, `L` and `O` (31 symbols), for values
+    /// that are read aloud or typed in by hand.
+    Unambiguous,
+}
+
+const UPPERCASE_ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+const UNAMBIGUOUS: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+const ALPHABETIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const ALPHANUMERIC: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const PRINTABLE_ASCII: &[u8] =
+    b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+impl Charset {
+    /// Returns the symbols of the character set.
+    pub fn symbols(&self) -> &'static [u8] {
+        match self {
+            Charset::Alphanumeric => ALPHANUMERIC,
+            Charset::Alphabetic => ALPHABETIC,
+            Charset::PrintableAscii => PRINTABLE_ASCII,
+            Charset::UppercaseAlphanumeric => UPPERCASE_ALPHANUMERIC,
+            Charset::Unambiguous => UNAMBIGUOUS,
+        }
+    }
+}
+
+impl fmt::Display for Charset {
+    /// Formats the character set as the lowercase name used by the CLI, e.g. `alphanumeric`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Charset::Alphanumeric => "alphanumeric",
+            Charset::Alphabetic => "alphabetic",
+            Charset::PrintableAscii => "printable",
+            Charset::UppercaseAlphanumeric => "upperalnum",
+            Charset::Unambiguous => "unambiguous",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Charset {
+    type Err = GenrsError;
+
+    /// Parses the lowercase names used by the CLI: `alphanumeric`, `alphabetic`, `printable`,
+    /// `upperalnum` or `unambiguous`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "alphanumeric" => Ok(Charset::Alphanumeric),
+            "alphabetic" => Ok(Charset::Alphabetic),
+            "printable" => Ok(Charset::PrintableAscii),
+            "upperalnum" => Ok(Charset::UppercaseAlphanumeric),
+            "unambiguous" => Ok(Charset::Unambiguous),
+            _ => Err(GenrsError::UnsupportedCharset(s.to_string())),
+        }
+    }
+}
+
+/// Generates a random, human-typeable password of the given length in characters.
+///
+/// Each character is drawn independently and uniformly from the given `Charset` using `OsRng`.
+/// Sampling uses rejection (via `rand::distributions::Uniform`) rather than `% charset_len`,
+/// so no character is more likely than another.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{generate_password, Charset};
+///
+/// let password = generate_password(24, Charset::PrintableAscii);
+/// assert_eq!(password.len(), 24);
+/// assert!(password.chars().all(|c| c.is_ascii_graphic()));
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_password(length: usize, charset: Charset) -> String {
+    generate_password_with_rng(length, charset, &mut OsRng)
+}
+
+/// Generates a random password like `generate_password`, using the provided RNG.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{generate_password_with_rng, Charset};
+/// use rand_chacha::rand_core::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+///
+/// let first = generate_password_with_rng(16, Charset::Alphanumeric, &mut ChaCha20Rng::seed_from_u64(7));
+/// let second = generate_password_with_rng(16, Charset::Alphanumeric, &mut ChaCha20Rng::seed_from_u64(7));
+/// assert_eq!(first, second);
+/// ```
+pub fn generate_password_with_rng<R: RngCore + CryptoRng>(length: usize, charset: Charset, rng: &mut R) -> String {
+    let symbols = charset.symbols();
+    let distribution = Uniform::new(0, symbols.len());
+    rng.sample_iter(distribution)
+        .take(length)
+        .map(|index| symbols[index] as char)
+        .collect()
+}
+
+/// Generates a license-key style value of `groups` groups of `group_len` characters, joined with
+/// `separator`, e.g. `7KQ2-MX9D-HT4W-PZ3N`.
+///
+/// Every character is drawn independently and uniformly from `alphabet` using `OsRng`. Use
+/// `Charset::Unambiguous` to leave out characters that are easily confused when typed in by hand.
+/// The entropy of the result is `entropy_bits(groups * group_len, alphabet)`, as the separators
+/// are fixed.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{entropy_bits, generate_license_key, Charset};
+///
+/// let key = generate_license_key(4, 4, Charset::Unambiguous, '-');
+/// assert_eq!(key.len(), 19);
+/// assert!(key.split('-').all(|group| group.len() == 4));
+/// assert!(!key.contains(['0', 'O', '1', 'I', 'L']));
+///
+/// // 16 characters from 31 symbols carry about 79.3 bits of entropy.
+/// assert!((entropy_bits(16, Charset::Unambiguous) - 79.3).abs() < 0.1);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_license_key(groups: usize, group_len: usize, alphabet: Charset, separator: char) -> String {
+    generate_license_key_with_rng(groups, group_len, alphabet, separator, &mut OsRng)
+}
+
+/// Generates a license-key style value like `generate_license_key`, using the provided RNG.
+pub fn generate_license_key_with_rng<R: RngCore + CryptoRng>(
+    groups: usize,
+    group_len: usize,
+    alphabet: Charset,
+    separator: char,
+    rng: &mut R,
+) -> String {
+    (0..groups)
+        .map(|_| generate_password_with_rng(group_len, alphabet, rng))
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+const UPPERCASE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const DIGITS: &[u8] = b"0123456789";
+const SYMBOLS: &[u8] = b"!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// Minimum number of characters from each character class that a generated password must contain.
+///
+/// The required characters are drawn from their own class (`A-Z`, `a-z`, `0-9` or the 32 ASCII
+/// symbols), the remaining characters from `charset`, and the result is shuffled so that the
+/// required characters end up at uniformly random positions.
+///
+/// # Entropy
+///
+/// Requiring a class means those characters come from a smaller set than `charset`, so a policy
+/// password carries slightly less entropy than an unconstrained password of the same length.
+/// `entropy_bits` returns a conservative estimate that ignores the extra entropy contributed
+/// by the random placement of the required characters. For 16 printable characters with one
+/// required character from each class, the estimate is about 96.4 bits, compared to 104.9 bits
+/// for an unconstrained password.
+///
+/// Refer to the `generate_policy_password` function for usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// The character set the remaining (unconstrained) characters are drawn from.
+    pub charset: Charset,
+    /// Minimum number of uppercase letters (`A-Z`).
+    pub min_uppercase: usize,
+    /// Minimum number of lowercase letters (`a-z`).
+    pub min_lowercase: usize,
+    /// Minimum number of digits (`0-9`).
+    pub min_digits: usize,
+    /// Minimum number of ASCII symbols (`!` through `~`, excluding letters and digits).
+    pub min_symbols: usize,
+}
+
+impl Default for PasswordPolicy {
+    /// An alphanumeric policy without any requirements, equivalent to `generate_password`.
+    fn default() -> Self {
+        PasswordPolicy {
+            charset: Charset::Alphanumeric,
+            min_uppercase: 0,
+            min_lowercase: 0,
+            min_digits: 0,
+            min_symbols: 0,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Returns the number of characters needed to satisfy every requirement.
+    pub fn required_length(&self) -> usize {
+        self.min_uppercase + self.min_lowercase + self.min_digits + self.min_symbols
+    }
+
+    /// Returns a conservative estimate of the entropy in bits of a password of the given length
+    /// generated with this policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::{entropy_bits, Charset, PasswordPolicy};
+    ///
+    /// let policy = PasswordPolicy { charset: Charset::PrintableAscii, min_uppercase: 1, min_lowercase: 1, min_digits: 1, min_symbols: 1 };
+    /// assert!((policy.entropy_bits(16) - 96.4).abs() < 0.1);
+    /// assert!(policy.entropy_bits(16) < entropy_bits(16, Charset::PrintableAscii));
+    ///
+    /// let unconstrained = PasswordPolicy { charset: Charset::PrintableAscii, ..Default::default() };
+    /// assert_eq!(unconstrained.entropy_bits(16), entropy_bits(16, Charset::PrintableAscii));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn entropy_bits(&self, length: usize) -> f64 {
+        let required: f64 = self
+            .classes()
+            .iter()
+            .map(|(symbols, count)| *count as f64 * (symbols.len() as f64).log2())
+            .sum();
+        required + entropy_bits(length.saturating_sub(self.required_length()), self.charset)
+    }
+
+    /// Returns the symbols and minimum count of every character class.
+    fn classes(&self) -> [(&'static [u8], usize); 4] {
+        [
+            (UPPERCASE, self.min_uppercase),
+            (LOWERCASE, self.min_lowercase),
+            (DIGITS, self.min_digits),
+            (SYMBOLS, self.min_symbols),
+        ]
+    }
+}
+
+/// Generates a random password of the given length in characters that satisfies the given policy.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{generate_policy_password, Charset, PasswordPolicy};
+///
+/// let policy = PasswordPolicy { min_uppercase: 1, min_digits: 2, min_symbols: 1, ..Default::default() };
+/// let password = generate_policy_password(12, policy);
+/// assert_eq!(password.len(), 12);
+/// assert!(password.chars().filter(|c| c.is_ascii_uppercase()).count() >= 1);
+/// assert!(password.chars().filter(|c| c.is_ascii_digit()).count() >= 2);
+/// assert!(password.chars().filter(|c| c.is_ascii_punctuation()).count() >= 1);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if `length` is smaller than `policy.required_length()`, or if the system's
+/// entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_policy_password(length: usize, policy: PasswordPolicy) -> String {
+    generate_policy_password_with_rng(length, policy, &mut OsRng)
+}
+
+/// Generates a policy password like `generate_policy_password`, using the provided RNG.
+///
+/// # Panics
+///
+/// Will panic if `length` is smaller than `policy.required_length()`.
+pub fn generate_policy_password_with_rng<R: RngCore + CryptoRng>(length: usize, policy: PasswordPolicy, rng: &mut R) -> String {
+    assert!(
+        policy.required_length() <= length,
+        "password policy requires at least {} characters, but the length is {}",
+        policy.required_length(),
+        length
+    );
+
+    let mut password = Vec::with_capacity(length);
+    for (symbols, count) in policy.classes() {
+        let distribution = Uniform::new(0, symbols.len());
+        password.extend((0..count).map(|_| symbols[rng.sample(distribution)]));
+    }
+    let symbols = policy.charset.symbols();
+    let distribution = Uniform::new(0, symbols.len());
+    password.extend((password.len()..length).map(|_| symbols[rng.sample(distribution)]));
+
+    password.shuffle(rng);
+    password.into_iter().map(char::from).collect()
+}
+
+/// Enum to represent the standard BIP39 mnemonic lengths.
+///
+/// Each length corresponds to a fixed amount of entropy: 12 words carry 128 bits, 24 words
+/// carry 256 bits.
+///
+/// Refer to the `generate_mnemonic` function for usage.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MnemonicLength {
+    Words12,
+    Words15,
+    Words18,
+    Words21,
+    Words24,
+}
+
+#[cfg(feature = "std")]
+impl MnemonicLength {
+    /// Returns the length matching the given number of words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::MnemonicLength;
+    ///
+    /// assert_eq!(MnemonicLength::from_word_count(24).unwrap(), MnemonicLength::Words24);
+    /// assert!(MnemonicLength::from_word_count(13).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `GenrsError::InvalidLength` if the word count is not 12, 15, 18, 21 or 24.
+    pub fn from_word_count(words: usize) -> Result<Self, GenrsError> {
+        match words {
+            12 => Ok(MnemonicLength::Words12),
+            15 => Ok(MnemonicLength::Words15),
+            18 => Ok(MnemonicLength::Words18),
+            21 => Ok(MnemonicLength::Words21),
+            24 => Ok(MnemonicLength::Words24),
+            _ => Err(GenrsError::InvalidLength(format!(
+                "a mnemonic must have 12, 15, 18, 21 or 24 words, got {}",
+                words
+            ))),
+        }
+    }
+
+    /// Returns the number of words in the mnemonic.
+    pub fn word_count(&self) -> usize {
+        self.entropy_bytes() * 3 / 4
+    }
+
+    /// Returns the number of entropy bytes encoded by the mnemonic.
+    pub fn entropy_bytes(&self) -> usize {
+        match self {
+            MnemonicLength::Words12 => 16,
+            MnemonicLength::Words15 => 20,
+            MnemonicLength::Words18 => 24,
+            MnemonicLength::Words21 => 28,
+            MnemonicLength::Words24 => 32,
+        }
+    }
+}
+
+/// Generates a BIP39 mnemonic phrase from the standard English wordlist.
+///
+/// The entropy is produced by `generate_key` and encoded with `mnemonic_from_entropy`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{generate_mnemonic, MnemonicLength};
+///
+/// let mnemonic = generate_mnemonic(MnemonicLength::Words24);
+/// assert_eq!(mnemonic.split(' ').count(), 24);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_mnemonic(word_count: MnemonicLength) -> String {
+    encode_mnemonic(&generate_key(word_count.entropy_bytes()))
+}
+
+/// Generates a BIP39 mnemonic phrase like `generate_mnemonic`, using the provided RNG for the entropy.
+#[cfg(feature = "std")]
+pub fn generate_mnemonic_with_rng<R: RngCore + CryptoRng>(word_count: MnemonicLength, rng: &mut R) -> String {
+    encode_mnemonic(&generate_key_with_rng(word_count.entropy_bytes(), rng))
+}
+
+/// Encodes the given entropy as a BIP39 mnemonic phrase from the standard English wordlist.
+///
+/// The first `ENT / 32` bits of the SHA-256 hash of the entropy are appended as a checksum,
+/// and the result is split into 11-bit groups, each selecting one of the 2048 words.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::mnemonic_from_entropy;
+///
+/// // Reference vectors from the BIP39 specification.
+/// assert_eq!(
+///     mnemonic_from_entropy(&[0x00; 16]).unwrap(),
+///     "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+/// );
+/// assert_eq!(
+///     mnemonic_from_entropy(&[0x7f; 16]).unwrap(),
+///     "legal winner thank year wave sausage worth useful legal winner thank yellow"
+/// );
+/// assert_eq!(
+///     mnemonic_from_entropy(&[0xff; 16]).unwrap(),
+///     "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"
+/// );
+/// assert!(mnemonic_from_entropy(&[0x00; 15]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidLength` if the entropy is not 16, 20, 24, 28 or 32 bytes long.
+#[cfg(feature = "std")]
+pub fn mnemonic_from_entropy(entropy: &[u8]) -> Result<String, GenrsError> {
+    if !(16..=32).contains(&entropy.len()) || !entropy.len().is_multiple_of(4) {
+        return Err(GenrsError::InvalidLength(format!(
+            "mnemonic entropy must be 16, 20, 24, 28 or 32 bytes, got {}",
+            entropy.len()
+        )));
+    }
+    Ok(encode_mnemonic(entropy))
+}
+
+/// Encodes entropy of a valid BIP39 length as a mnemonic phrase.
+#[cfg(feature = "std")]
+fn encode_mnemonic(entropy: &[u8]) -> String {
+    let words = Language::English.word_list();
+    let checksum = Sha256::digest(entropy);
+    let entropy_bits = entropy.len() * 8;
+    let total_bits = entropy_bits + entropy_bits / 32;
+
+    let bit = |i: usize| -> usize {
+        let (bytes, i) = if i < entropy_bits { (entropy, i) } else { (&checksum[..], i - entropy_bits) };
+        ((bytes[i / 8] >> (7 - i % 8)) & 1) as usize
+    };
+
+    (0..total_bits / 11)
+        .map(|word| words[(0..11).fold(0, |index, offset| (index << 1) | bit(word * 11 + offset))])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Generates a diceware-style passphrase from the EFF large wordlist.
+///
+/// Each word is drawn uniformly from the 7776 words of the wordlist using `OsRng`, and the words
+/// are joined with `separator`. Every word adds `log2(7776) ≈ 12.9` bits of entropy; use
+/// `passphrase_entropy_bits` to compute the total.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::generate_passphrase;
+///
+/// let passphrase = generate_passphrase(6, "-");
+/// assert_eq!(passphrase.split('-').count(), 6);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_passphrase(words: usize, separator: &str) -> String {
+    generate_passphrase_with_rng(words, separator, &mut OsRng)
+}
+
+/// Generates a diceware-style passphrase like `generate_passphrase`, using the provided RNG.
+#[cfg(feature = "std")]
+pub fn generate_passphrase_with_rng<R: RngCore + CryptoRng>(words: usize, separator: &str, rng: &mut R) -> String {
+    let wordlist = &eff_wordlist::large::LIST;
+    let distribution = Uniform::new(0, wordlist.len());
+    rng.sample_iter(distribution)
+        .take(words)
+        .map(|index| wordlist[index].1)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Returns the entropy in bits of a passphrase with the given number of words, as generated by
+/// `generate_passphrase`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::passphrase_entropy_bits;
+///
+/// assert!((passphrase_entropy_bits(6) - 77.55).abs() < 0.01);
+/// ```
+#[cfg(feature = "std")]
+pub fn passphrase_entropy_bits(words: usize) -> f64 {
+    words as f64 * (eff_wordlist::large::LIST.len() as f64).log2()
+}
+
+/// Enum to represent the set of symbols a generated value is drawn from, for entropy calculations.
+///
+/// `EncodingFormat` and `Charset` convert into this type, so either can be passed to `entropy_bits`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolSet {
+    /// Raw random bytes; the encoding format does not change the entropy.
+    Bytes,
+    /// Characters drawn uniformly from a character set.
+    Charset(Charset),
+}
+
+#[cfg(feature = "std")]
+impl From<EncodingFormat> for SymbolSet {
+    fn from(_: EncodingFormat) -> Self {
+        SymbolSet::Bytes
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Charset> for SymbolSet {
+    fn from(charset: Charset) -> Self {
+        SymbolSet::Charset(charset)
+    }
+}
+
+/// Returns the entropy in bits of a uniformly random value of the given length.
+///
+/// For raw keys, `length` is in bytes and every byte carries 8 bits, regardless of the encoding.
+/// For charset-based values, `length` is in characters and every character carries
+/// `log2(charset size)` bits.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{entropy_bits, Charset, EncodingFormat};
+///
+/// assert_eq!(entropy_bits(32, EncodingFormat::Hex), 256.0);
+/// assert_eq!(entropy_bits(16, Charset::Alphanumeric), 16.0 * 62f64.log2());
+/// ```
+#[cfg(feature = "std")]
+pub fn entropy_bits(length: usize, symbols: impl Into<SymbolSet>) -> f64 {
+    match symbols.into() {
+        SymbolSet::Bytes => (length * 8) as f64,
+        SymbolSet::Charset(charset) => length as f64 * (charset.symbols().len() as f64).log2(),
+    }
+}
+
+/// Returns a warning if a secret with the given entropy is considered weak, or `None` if it is
+/// strong enough for cryptographic use (at least 128 bits).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use genrs_lib::{entropy_bits, strength_warning, Charset, EncodingFormat};
+///
+/// assert_eq!(strength_warning(entropy_bits(32, EncodingFormat::Hex)), None);
+/// assert_eq!(
+///     strength_warning(entropy_bits(16, Charset::Alphanumeric)),
+///     Some("below 128 bits; not recommended for secrets")
+/// );
+/// assert_eq!(
+///     strength_warning(entropy_bits(8, Charset::Alphabetic)),
+///     Some("below 64 bits; easily brute-forced, do not use for secrets")
+/// );
+/// # }
+/// ```
+pub fn strength_warning(bits: f64) -> Option<&'static str> {
+    if bits < 64.0 {
+        Some("below 64 bits; easily brute-forced, do not use for secrets")
+    } else if bits < 128.0 {
+        Some("below 128 bits; not recommended for secrets")
+    } else {
+        None
+    }
+}
+
+/// Enum to represent UUID versions.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::{generate_uuid, UuidVersion};
+///
+/// let uuid_v4 = generate_uuid(UuidVersion::V4, None, None).unwrap();
+/// println!("Generated UUID V4: {}", uuid_v4);
+/// ```
+///
+/// Refer to the `generate_uuid` function for usage.
+///
+/// With the `serde` feature enabled, the variants (de)serialize as the lowercase names used by
+/// the CLI (e.g. `"v4"`), while `V8` is represented as `{"v8": [/* 16 bytes */]}`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum UuidVersion {
+    V1,
+    V3,
+    V4,
+    V5,
+    V6,
+    V7,
+    /// Custom, application-defined UUID built from the given 16 bytes. The version and variant
+    /// bits are overwritten, leaving 122 bits of payload.
+    V8([u8; 16]),
+    /// The all-zeros nil UUID, `00000000-0000-0000-0000-000000000000`.
+    Nil,
+    /// The all-ones max UUID, `ffffffff-ffff-ffff-ffff-ffffffffffff`.
+    Max,
+}
+
+#[cfg(feature = "std")]
+impl UuidVersion {
+    /// Creates a `UuidVersion::V8` from a slice of custom bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::{generate_uuid, UuidVersion};
+    ///
+    /// let version = UuidVersion::v8_from_slice(&[0xab; 16]).unwrap();
+    /// let uuid_v8 = generate_uuid(version, None, None).unwrap();
+    /// assert_eq!(uuid_v8.get_version_num(), 8);
+    ///
+    /// assert!(UuidVersion::v8_from_slice(&[0xab; 15]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `GenrsError::InvalidLength` if the slice is not exactly 16 bytes long.
+    pub fn v8_from_slice(bytes: &[u8]) -> Result<Self, GenrsError> {
+        let custom: [u8; 16] = bytes.try_into().map_err(|_| {
+            GenrsError::InvalidLength(format!("UUID V8 requires exactly 16 custom bytes, got {}", bytes.len()))
+        })?;
+        Ok(UuidVersion::V8(custom))
+    }
+
+    /// Returns `true` for the name-based versions V3 and V5, the only ones derived from a
+    /// namespace and name. All other versions ignore them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::UuidVersion;
+    ///
+    /// assert!(UuidVersion::V5.is_name_based());
+    /// assert!(!UuidVersion::V4.is_name_based());
+    /// ```
+    pub fn is_name_based(&self) -> bool {
+        matches!(self, UuidVersion::V3 | UuidVersion::V5)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for UuidVersion {
+    /// Formats the version as the canonical lowercase name used by the CLI, e.g. `v4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::UuidVersion;
+    ///
+    /// assert_eq!(UuidVersion::V7.to_string(), "v7");
+    /// assert_eq!("v7".parse::<UuidVersion>().unwrap(), UuidVersion::V7);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            UuidVersion::V1 => "v1",
+            UuidVersion::V3 => "v3",
+            UuidVersion::V4 => "v4",
+            UuidVersion::V5 => "v5",
+            UuidVersion::V6 => "v6",
+            UuidVersion::V7 => "v7",
+            UuidVersion::V8(_) => "v8",
+            UuidVersion::Nil => "nil",
+            UuidVersion::Max => "max",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for UuidVersion {
+    type Err = GenrsError;
+
+    /// Parses the lowercase version names used by the CLI, e.g. `v4` or `v7`.
+    ///
+    /// `v8` cannot be parsed from a string because it carries custom bytes; use
+    /// `UuidVersion::v8_from_slice` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use genrs_lib::UuidVersion;
+    ///
+    /// assert_eq!("v4".parse::<UuidVersion>().unwrap(), UuidVersion::V4);
+    /// assert!("v9".parse::<UuidVersion>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "v1" => Ok(UuidVersion::V1),
+            "v3" => Ok(UuidVersion::V3),
+            "v4" => Ok(UuidVersion::V4),
+            "v5" => Ok(UuidVersion::V5),
+            "v6" => Ok(UuidVersion::V6),
+            "v7" => Ok(UuidVersion::V7),
+            "nil" => Ok(UuidVersion::Nil),
+            "max" => Ok(UuidVersion::Max),
+            _ => Err(GenrsError::UnsupportedUuidVersion(s.to_string())),
+        }
+    }
+}
+
+/// Generates a V5 UUID for a name in the standard DNS namespace, e.g. a hostname.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::uuid_v5_dns;
+///
+/// assert_eq!(uuid_v5_dns("example.com"), Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"example.com"));
+/// ```
+#[cfg(feature = "std")]
+pub fn uuid_v5_dns(name: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_DNS, name.as_bytes())
+}
+
+/// Generates a V5 UUID for a name in the standard URL namespace.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::uuid_v5_url;
+///
+/// assert_eq!(uuid_v5_url("https://example.com/"), Uuid::new_v5(&Uuid::NAMESPACE_URL, b"https://example.com/"));
+/// ```
+#[cfg(feature = "std")]
+pub fn uuid_v5_url(name: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_URL, name.as_bytes())
+}
+
+/// Generates a V5 UUID for a name in the standard ISO OID namespace.
+#[cfg(feature = "std")]
+pub fn uuid_v5_oid(name: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_OID, name.as_bytes())
+}
+
+/// Generates a V5 UUID for a name in the standard X.500 DN namespace.
+#[cfg(feature = "std")]
+pub fn uuid_v5_x500(name: &str) -> Uuid {
+    Uuid::new_v5(&Uuid::NAMESPACE_X500, name.as_bytes())
+}
+
+/// The separator `uuid_v5_path` places between name segments.
+#[cfg(feature = "std")]
+pub const UUID_PATH_SEPARATOR: &str = "/";
+
+/// Generates a V5 UUID for a hierarchical name made of several segments, e.g.
+/// `["tenant", "resource", "id"]`.
+///
+/// The canonical name is the UTF-8 bytes of the segments joined with `UUID_PATH_SEPARATOR`
+/// (`/`), without a leading or trailing separator and without any escaping or normalization,
+/// so the result is exactly `Uuid::new_v5(&namespace, parts.join("/").as_bytes())` and can be
+/// reproduced by any V5 implementation. As a consequence, segments that themselves contain `/`
+/// are indistinguishable from the split path: `["a/b"]` and `["a", "b"]` yield the same UUID.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::uuid_v5_path;
+///
+/// let uuid = uuid_v5_path(Uuid::NAMESPACE_URL, &["tenant", "resource", "id"]);
+/// assert_eq!(uuid, Uuid::new_v5(&Uuid::NAMESPACE_URL, b"tenant/resource/id"));
+/// assert_eq!(uuid, uuid_v5_path(Uuid::NAMESPACE_URL, &["tenant", "resource", "id"]));
+/// assert_ne!(uuid, uuid_v5_path(Uuid::NAMESPACE_URL, &["tenant", "resource"]));
+/// ```
+#[cfg(feature = "std")]
+pub fn uuid_v5_path(namespace: Uuid, parts: &[&str]) -> Uuid {
+    Uuid::new_v5(&namespace, parts.join(UUID_PATH_SEPARATOR).as_bytes())
+}
+
+/// Enum to represent the textual representation of a UUID.
+///
+/// Refer to the `format_uuid` function for usage.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UuidFormat {
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    #[default]
+    Hyphenated,
+    /// `67e5504410b1426f9247bb680e5fe0c8`
+    Simple,
+    /// `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Urn,
+    /// `{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+    Braced,
+    /// `LVfaprxxmb7zm5pCtsRvgs`, see `uuid_to_short`
+    Short,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for UuidFormat {
+    /// Formats the UUID format as the lowercase name used by the CLI, e.g. `simple`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            UuidFormat::Hyphenated => "hyphenated",
+            UuidFormat::Simple => "simple",
+            UuidFormat::Urn => "urn",
+            UuidFormat::Braced => "braced",
+            UuidFormat::Short => "short",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for UuidFormat {
+    type Err = GenrsError;
+
+    /// Parses the lowercase names used by the CLI: `hyphenated`, `simple`, `urn`, `braced` or
+    /// `short`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "hyphenated" => Ok(UuidFormat::Hyphenated),
+            "simple" => Ok(UuidFormat::Simple),
+            "urn" => Ok(UuidFormat::Urn),
+            "braced" => Ok(UuidFormat::Braced),
+            "short" => Ok(UuidFormat::Short),
+            _ => Err(GenrsError::UnsupportedFormat(s.to_string())),
+        }
+    }
+}
+
+/// Formats a UUID in the given textual representation.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::{format_uuid, UuidFormat};
+///
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_eq!(format_uuid(&uuid, UuidFormat::Simple), "67e5504410b1426f9247bb680e5fe0c8");
+/// assert_eq!(format_uuid(&uuid, UuidFormat::Urn), "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert_eq!(format_uuid(&uuid, UuidFormat::Braced), "{67e55044-10b1-426f-9247-bb680e5fe0c8}");
+/// assert_eq!(format_uuid(&uuid, UuidFormat::Short), "LVfaprxxmb7zm5pCtsRvgs");
+/// ```
+#[cfg(feature = "std")]
+pub fn format_uuid(uuid: &Uuid, fmt: UuidFormat) -> String {
+    match fmt {
+        UuidFormat::Hyphenated => uuid.hyphenated().to_string(),
+        UuidFormat::Simple => uuid.simple().to_string(),
+        UuidFormat::Urn => uuid.urn().to_string(),
+        UuidFormat::Braced => uuid.braced().to_string(),
+        UuidFormat::Short => uuid_to_short(uuid),
+    }
+}
+
+/// Formats a UUID in the given textual representation with uppercase hex digits.
+///
+/// The `urn:uuid:` prefix of the URN notation stays lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::{format_uuid_uppercase, UuidFormat};
+///
+/// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+/// assert_eq!(format_uuid_uppercase(&uuid, UuidFormat::Hyphenated).unwrap(), "67E55044-10B1-426F-9247-BB680E5FE0C8");
+/// assert_eq!(format_uuid_uppercase(&uuid, UuidFormat::Urn).unwrap(), "urn:uuid:67E55044-10B1-426F-9247-BB680E5FE0C8");
+/// assert!(format_uuid_uppercase(&uuid, UuidFormat::Short).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::UnsupportedFormat` for `UuidFormat::Short`, whose base57 alphabet is
+/// case-sensitive.
+#[cfg(feature = "std")]
+pub fn format_uuid_uppercase(uuid: &Uuid, fmt: UuidFormat) -> Result<String, GenrsError> {
+    let mut buffer = Uuid::encode_buffer();
+    match fmt {
+        UuidFormat::Hyphenated => Ok(uuid.hyphenated().encode_upper(&mut buffer).to_string()),
+        UuidFormat::Simple => Ok(uuid.simple().encode_upper(&mut buffer).to_string()),
+        UuidFormat::Urn => Ok(uuid.urn().encode_upper(&mut buffer).to_string()),
+        UuidFormat::Braced => Ok(uuid.braced().encode_upper(&mut buffer).to_string()),
+        UuidFormat::Short => Err(GenrsError::UnsupportedFormat(
+            "short UUIDs are case-sensitive and cannot be uppercased".to_string(),
+        )),
+    }
+}
+
+/// The base57 alphabet used for short UUIDs, compatible with the `shortuuid` libraries. It leaves
+/// out the easily confused characters `0`, `1`, `I`, `O` and `l`.
+#[cfg(feature = "std")]
+pub const SHORT_UUID_ALPHABET: &[u8; 57] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The length of a short UUID: the number of base57 digits needed to represent 128 bits.
+#[cfg(feature = "std")]
+pub const SHORT_UUID_LENGTH: usize = 22;
+
+/// Encodes a UUID as a 22 character base57 "short UUID".
+///
+/// The digits are written most significant first and left-padded with the zero digit (`2`), so
+/// every UUID, including ones with leading zero bytes, maps to exactly one short UUID. Use
+/// `short_to_uuid` to convert it back.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::{short_to_uuid, uuid_to_short};
+///
+/// let uuid = Uuid::new_v4();
+/// let short = uuid_to_short(&uuid);
+/// assert_eq!(short.len(), 22);
+/// assert_eq!(short_to_uuid(&short).unwrap(), uuid);
+///
+/// assert_eq!(uuid_to_short(&Uuid::nil()), "2222222222222222222222");
+/// assert_eq!(short_to_uuid("2222222222222222222222").unwrap(), Uuid::nil());
+/// assert_eq!(short_to_uuid(&uuid_to_short(&Uuid::from_u128(u128::MAX))).unwrap(), Uuid::from_u128(u128::MAX));
+/// ```
+#[cfg(feature = "std")]
+pub fn uuid_to_short(uuid: &Uuid) -> String {
+    let mut value = uuid.as_u128();
+    let mut digits = [SHORT_UUID_ALPHABET[0]; SHORT_UUID_LENGTH];
+    for digit in digits.iter_mut().rev() {
+        *digit = SHORT_UUID_ALPHABET[(value % 57) as usize];
+        value /= 57;
+    }
+    digits.iter().map(|&digit| digit as char).collect()
+}
+
+/// Decodes a base57 short UUID produced by `uuid_to_short`.
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidEncoding` if the input is not exactly 22 characters long, contains
+/// characters outside `SHORT_UUID_ALPHABET`, or encodes a value larger than 128 bits.
+#[cfg(feature = "std")]
+pub fn short_to_uuid(short: &str) -> Result<Uuid, GenrsError> {
+    if short.len() != SHORT_UUID_LENGTH {
+        return Err(GenrsError::InvalidEncoding(format!(
+            "a short UUID must be {} characters long, got {}",
+            SHORT_UUID_LENGTH,
+            short.len()
+        )));
+    }
+    let mut value: u128 = 0;
+    for c in short.bytes() {
+        let digit = SHORT_UUID_ALPHABET
+            .iter()
+            .position(|&d| d == c)
+            .ok_or_else(|| GenrsError::InvalidEncoding(format!("invalid short UUID character '{}'", c as char)))?;
+        value = value
+            .checked_mul(57)
+            .and_then(|value| value.checked_add(digit as u128))
+            .ok_or_else(|| GenrsError::InvalidEncoding(format!("short UUID '{}' exceeds 128 bits", short)))?;
+    }
+    Ok(Uuid::from_u128(value))
+}
+
+/// Extracts the creation time embedded in a time-based (V1, V6 or V7) UUID and formats it as an
+/// RFC 3339 timestamp in UTC.
+///
+/// V1 and V6 UUIDs store the time with 100ns precision and V7 UUIDs with millisecond precision;
+/// trailing zeros of the fractional seconds are omitted. Returns `None` for UUID versions that
+/// carry no timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::uuid_timestamp;
+///
+/// // Test vectors from RFC 9562.
+/// let v1 = Uuid::parse_str("C232AB00-9414-11EC-B3C8-9F6BDECED846").unwrap();
+/// let v6 = Uuid::parse_str("1EC9414C-232A-6B00-B3C8-9F6BDECED846").unwrap();
+/// let v7 = Uuid::parse_str("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+/// assert_eq!(uuid_timestamp(&v1).unwrap(), "2022-02-22T19:22:22Z");
+/// assert_eq!(uuid_timestamp(&v6).unwrap(), "2022-02-22T19:22:22Z");
+/// assert_eq!(uuid_timestamp(&v7).unwrap(), "2022-02-22T19:22:22Z");
+///
+/// assert_eq!(uuid_timestamp(&Uuid::new_v4()), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn uuid_timestamp(uuid: &Uuid) -> Option<String> {
+    let (seconds, nanos) = uuid.get_timestamp()?.to_unix();
+    Some(format_rfc3339(seconds, nanos))
+}
+
+/// Formats seconds and nanoseconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+#[cfg(feature = "std")]
+fn format_rfc3339(seconds: u64, nanos: u32) -> String {
+    // Converts days since the Unix epoch to a proleptic Gregorian date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let time_of_day = seconds % 86_400;
+    let mut timestamp = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time_of_day / 3600,
+        time_of_day / 60 % 60,
+        time_of_day % 60
+    );
+    if nanos > 0 {
+        timestamp.push_str(format!(".{:09}", nanos).trim_end_matches('0'));
+    }
+    timestamp.push('Z');
+    timestamp
+}
+
+/// Parses a UUID namespace, accepting either one of the well-known namespace keywords
+/// (`dns`, `url`, `oid`, `x500`, case-insensitive) or a literal UUID string.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::parse_namespace;
+///
+/// assert_eq!(parse_namespace("dns").unwrap(), Uuid::NAMESPACE_DNS);
+/// assert_eq!(parse_namespace("URL").unwrap(), Uuid::NAMESPACE_URL);
+/// assert_eq!(
+///     parse_namespace("6ba7b812-9dad-11d1-80b4-00c04fd430c8").unwrap(),
+///     Uuid::NAMESPACE_OID
+/// );
+/// assert!(parse_namespace("not-a-namespace").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::InvalidNamespace` if the value is neither a known keyword nor a valid UUID.
+#[cfg(feature = "std")]
+pub fn parse_namespace(value: &str) -> Result<Uuid, GenrsError> {
+    match value.to_ascii_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        _ => Uuid::parse_str(value).map_err(|_| GenrsError::InvalidNamespace(value.to_string())),
+    }
+}
+
+/// Generates a UUID of the specified version.
+///
+/// - **UUID V1**: Generates a UUID based on the current system time and a random node ID.
+///   Use `generate_time_uuid` to supply an explicit node ID and timestamp.
+/// - **UUID V3 and V5**: Require a namespace and name for generating a UUID based on the MD5 or SHA-1 hash.
+///   Use `parse_namespace` to resolve well-known namespaces such as `dns` or `url`.
+/// - **UUID V4**: Generates a purely random UUID.
+/// - **UUID V6**: Like V1, but with the timestamp fields reordered so that the UUIDs are sortable.
+/// - **UUID V7**: Generates a time-ordered UUID from the current Unix timestamp and random bits,
+///   which sorts chronologically and is well suited for database primary keys.
+/// - **UUID V8**: Builds a UUID from the custom bytes carried by the variant.
+/// - **Nil and Max**: Return the all-zeros and all-ones UUIDs, e.g. as sentinels or range bounds.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::{generate_uuid, UuidVersion};
+///
+/// let uuid_v1 = generate_uuid(UuidVersion::V1, None, None).unwrap();
+/// println!("Generated UUID V1: {}", uuid_v1);
+///
+/// let namespace = Uuid::new_v4();
+/// let uuid_v3 = generate_uuid(UuidVersion::V3, Some(namespace), Some("example")).unwrap();
+/// println!("Generated UUID V3: {}", uuid_v3);
+///
+/// let uuid_v6 = generate_uuid(UuidVersion::V6, None, None).unwrap();
+/// assert_eq!(uuid_v6.get_version_num(), 6);
+///
+/// let uuid_v7 = generate_uuid(UuidVersion::V7, None, None).unwrap();
+/// assert_eq!(uuid_v7.get_version_num(), 7);
+///
+/// assert!(generate_uuid(UuidVersion::Nil, None, None).unwrap().is_nil());
+/// assert_eq!(generate_uuid(UuidVersion::Max, None, None).unwrap().to_string(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::MissingNamespace` or `GenrsError::MissingName` if the required parameters
+/// for UUID V3 or V5 are missing.
+#[cfg(feature = "std")]
+pub fn generate_uuid(version: UuidVersion, namespace: Option<Uuid>, name: Option<&str>) -> Result<Uuid, GenrsError> {
+    generate_uuid_bytes(version, namespace, name.map(str::as_bytes))
+}
+
+/// Generates a time-based (V1 or V6) UUID from an explicit node ID and/or timestamp.
+///
+/// When `node_id` is `None`, a random node ID is used; when `timestamp` is `None`, the current
+/// system time with a random clock sequence is used. This matches `generate_uuid`, while allowing
+/// exact UUID values to be reproduced from known inputs.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Timestamp;
+/// use genrs_lib::{generate_time_uuid, UuidVersion};
+///
+/// let node_id = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+/// let ts = Timestamp::from_gregorian_time(0x01ec_9414_c232_ab00, 0x1234);
+///
+/// let uuid_v1 = generate_time_uuid(UuidVersion::V1, Some(node_id), Some(ts)).unwrap();
+/// assert_eq!(uuid_v1.to_string(), "c232ab00-9414-11ec-9234-010203040506");
+///
+/// let uuid_v6 = generate_time_uuid(UuidVersion::V6, Some(node_id), Some(ts)).unwrap();
+/// assert_eq!(uuid_v6.to_string(), "1ec9414c-232a-6b00-9234-010203040506");
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::UnsupportedUuidVersion` if the version is not V1 or V6.
+#[cfg(feature = "std")]
+pub fn generate_time_uuid(
+    version: UuidVersion,
+    node_id: Option<[u8; 6]>,
+    timestamp: Option<Timestamp>,
+) -> Result<Uuid, GenrsError> {
+    let node_id = node_id.unwrap_or_else(|| OsRng.gen());
+    let ts = timestamp.unwrap_or_else(|| Timestamp::now(ContextV1::new(OsRng.next_u64() as u16)));
+
+    match version {
+        UuidVersion::V1 => Ok(Uuid::new_v1(ts, &node_id)),
+        UuidVersion::V6 => Ok(Uuid::new_v6(ts, &node_id)),
+        _ => Err(GenrsError::UnsupportedUuidVersion(format!(
+            "{} (only v1 and v6 are time-based with a node ID)",
+            version
+        ))),
+    }
+}
+
+/// Generates a batch of UUIDs of the specified version.
+///
+/// For V1 and V6, a single clock sequence `Context` and node ID are shared across the whole
+/// batch, so UUIDs generated within the same timestamp tick still receive distinct clock
+/// sequence values instead of relying on a fresh random context for every UUID.
+/// V4 UUIDs are drawn in bulk via `generate_uuid_v4_batch`.
+/// All other versions behave like repeated calls to `generate_uuid`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use genrs_lib::{generate_uuids, UuidVersion};
+///
+/// let uuids = generate_uuids(UuidVersion::V1, 10_000, None, None).unwrap();
+/// let unique: HashSet<_> = uuids.iter().collect();
+/// assert_eq!(unique.len(), 10_000);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as `generate_uuid`.
+#[cfg(feature = "std")]
+pub fn generate_uuids(
+    version: UuidVersion,
+    count: usize,
+    namespace: Option<Uuid>,
+    name: Option<&str>,
+) -> Result<Vec<Uuid>, GenrsError> {
+    match version {
+        UuidVersion::V1 | UuidVersion::V6 => {
+            let context = ContextV1::new(OsRng.next_u64() as u16);
+            let node_id: [u8; 6] = OsRng.gen();
+
+            Ok((0..count)
+                .map(|_| {
+                    let ts = Timestamp::now(&context);
+                    if version == UuidVersion::V1 {
+                        Uuid::new_v1(ts, &node_id)
+                    } else {
+                        Uuid::new_v6(ts, &node_id)
+                    }
+                })
+                .collect())
+        }
+        UuidVersion::V4 => Ok(generate_uuid_v4_batch(count)),
+        _ => (0..count).map(|_| generate_uuid(version, namespace, name)).collect(),
+    }
+}
+
+/// Generates `count` random (V4) UUIDs from a single draw of random bytes.
+///
+/// `Uuid::new_v4` requests 16 bytes from the entropy source per UUID. This function instead fills
+/// one buffer of `count * 16` bytes with a single `OsRng` call, like `generate_keys_packed`, and
+/// sets the version and variant bits of every 16-byte chunk.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use genrs_lib::generate_uuid_v4_batch;
+/// use uuid::{Variant, Version};
+///
+/// let uuids = generate_uuid_v4_batch(1000);
+/// assert_eq!(uuids.len(), 1000);
+///
+/// for uuid in &uuids {
+///     assert_eq!(uuid.get_version(), Some(Version::Random));
+///     assert_eq!(uuid.get_variant(), Variant::RFC4122);
+///
+///     let text = uuid.hyphenated().to_string();
+///     assert_eq!(&text[14..15], "4");
+///     assert!(matches!(&text[19..20], "8" | "9" | "a" | "b"));
+/// }
+///
+/// let unique: HashSet<_> = uuids.iter().collect();
+/// assert_eq!(unique.len(), 1000);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if `count * 16` overflows `usize` or if the system's entropy source is unavailable.
+#[cfg(feature = "std")]
+pub fn generate_uuid_v4_batch(count: usize) -> Vec<Uuid> {
+    let packed = generate_keys_packed(count, 16);
+    packed
+        .chunks_exact(16)
+        .map(|chunk| {
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(chunk);
+            Builder::from_random_bytes(bytes).into_uuid()
+        })
+        .collect()
+}
+
+/// Builds a V4 UUID from 16 bytes of the provided RNG, e.g. for reproducible test fixtures.
+///
+/// **WARNING: a UUID from a seeded RNG is NOT random and NOT unique across runs.** Anyone who
+/// knows the seed can reproduce it, and every run with the same seed yields the same sequence.
+/// Use it only for test fixtures; `generate_uuid` and `generate_uuid_v4_batch` draw from `OsRng`.
+/// Like `generate_key_with_insecure_rng`, this function does not require `CryptoRng`.
+///
+/// # Examples
+///
+/// ```
+/// use genrs_lib::deterministic_uuid_v4;
+/// use rand_chacha::rand_core::SeedableRng;
+/// use rand_chacha::ChaCha20Rng;
+///
+/// let first = deterministic_uuid_v4(&mut ChaCha20Rng::seed_from_u64(1));
+/// let second = deterministic_uuid_v4(&mut ChaCha20Rng::seed_from_u64(1));
+/// assert_eq!(first, second);
+/// assert_eq!(first.get_version_num(), 4);
+/// ```
+///
+/// # Panics
+///
+/// Will panic if the RNG fails to produce random bytes.
+#[cfg(feature = "std")]
+pub fn deterministic_uuid_v4(rng: &mut impl RngCore) -> Uuid {
+    let mut bytes = [0u8; 16];
+    rng.try_fill_bytes(&mut bytes).expect("Failed to generate random bytes for the UUID");
+    Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// A thread-safe generator for time-based (V1, V6 and V7) UUIDs that keeps its clock state and
+/// node ID across calls.
+///
+/// `generate_uuid` creates a fresh random context for every UUID, so two UUIDs generated within
+/// the same 100ns tick only differ by chance. A `UuidGenerator` instead remembers the last
+/// timestamp it handed out and never reuses it: when the clock has not advanced (or has gone
+/// backwards), the timestamp is bumped by one tick. V1 and V6 UUIDs from the same generator are
+/// therefore always distinct, and V6 UUIDs sort in generation order. V7 UUIDs come from
+/// `Uuid::now_v7`, whose process-wide counter keeps them distinct and in generation order
+/// within the same millisecond; the remaining bits are random.
+///
+/// The generator is `Send + Sync` and all methods take `&self`, so a single instance can be
+/// shared between threads through an `Arc`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use genrs_lib::UuidGenerator;
+///
+/// let generator = UuidGenerator::new();
+/// let uuids: HashSet<_> = (0..10_000).map(|_| generator.next_v1()).collect();
+/// assert_eq!(uuids.len(), 10_000);
+///
+/// let generator = UuidGenerator::with_node_id([0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+/// let first = generator.next_v6();
+/// let second = generator.next_v6();
+/// assert_eq!(first.get_version_num(), 6);
+/// assert!(first.to_string().ends_with("010203040506"));
+/// assert!(first < second);
+///
+/// let v7: Vec<_> = (0..1_000).map(|_| generator.next_v7()).collect();
+/// assert!(v7.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+///
+/// Sharing one generator between many threads:
+///
+/// ```
+/// use std::collections::HashSet;
+/// use std::sync::Arc;
+/// use std::thread;
+/// use genrs_lib::UuidGenerator;
+///
+/// const THREADS: usize = 8;
+/// const PER_THREAD: usize = 62_500;
+///
+/// let generator = Arc::new(UuidGenerator::new());
+/// let handles: Vec<_> = (0..THREADS)
+///     .map(|_| {
+///         let generator = Arc::clone(&generator);
+///         thread::spawn(move || {
+///             (0..PER_THREAD)
+///                 .flat_map(|_| [generator.next_v1(), generator.next_v7()])
+///                 .collect::<Vec<_>>()
+///         })
+///     })
+///     .collect();
+///
+/// let mut uuids = HashSet::new();
+/// for handle in handles {
+///     for uuid in handle.join().unwrap() {
+///         assert!(uuids.insert(uuid), "duplicate UUID {}", uuid);
+///     }
+/// }
+/// assert_eq!(uuids.len(), THREADS * PER_THREAD * 2);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct UuidGenerator {
+    last_ticks: Mutex<u64>,
+    clock_seq: u16,
+    node_id: [u8; 6],
+}
+
+#[cfg(feature = "std")]
+impl UuidGenerator {
+    /// Creates a generator with a random node ID and a random clock sequence.
+    pub fn new() -> Self {
+        Self::with_node_id(OsRng.gen())
+    }
+
+    /// Creates a generator with the given node ID (e.g. a MAC address) and a random clock
+    /// sequence.
+    pub fn with_node_id(node_id: [u8; 6]) -> Self {
+        UuidGenerator {
+            last_ticks: Mutex::new(0),
+            // RFC 4122 clock sequences are 14 bits wide.
+            clock_seq: OsRng.gen::<u16>() & 0x3FFF,
+            node_id,
+        }
+    }
+
+    /// Returns the node ID embedded in the generated UUIDs.
+    pub fn node_id(&self) -> [u8; 6] {
+        self.node_id
+    }
+
+    /// Generates the next V1 UUID from the current system time.
+    pub fn next_v1(&self) -> Uuid {
+        Uuid::new_v1(self.next_timestamp(), &self.node_id)
+    }
+
+    /// Generates the next V6 UUID from the current system time.
+    pub fn next_v6(&self) -> Uuid {
+        Uuid::new_v6(self.next_timestamp(), &self.node_id)
+    }
+
+    /// Generates the next V7 UUID from the current system time.
+    ///
+    /// V7 UUIDs have no clock sequence or node ID, so this does not use the generator's state.
+    pub fn next_v7(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+
+    /// Returns a timestamp strictly greater than any previously returned by this generator.
+    fn next_timestamp(&self) -> Timestamp {
+        let (now, _) = Timestamp::now(NoContext).to_gregorian();
+
+        // The guarded value is a plain integer, so a panic in another thread cannot leave it
+        // in an inconsistent state.
+        let mut last_ticks = self.last_ticks.lock().unwrap_or_else(PoisonError::into_inner);
+        let ticks = now.max(*last_ticks + 1);
+        *last_ticks = ticks;
+        Timestamp::from_gregorian_time(ticks, self.clock_seq)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for UuidGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generates a UUID of the specified version, taking the V3/V5 name as raw bytes.
+///
+/// This behaves exactly like `generate_uuid`, but allows hashing arbitrary binary data such as
+/// file hashes or serialized messages, which are not necessarily valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use uuid::Uuid;
+/// use genrs_lib::{generate_uuid, generate_uuid_bytes, UuidVersion};
+///
+/// let digest = [0xde_u8, 0xad, 0xbe, 0xef, 0xff];
+/// let uuid_v5 = generate_uuid_bytes(UuidVersion::V5, Some(Uuid::NAMESPACE_OID), Some(&digest[..])).unwrap();
+/// assert_eq!(uuid_v5, Uuid::new_v5(&Uuid::NAMESPACE_OID, &digest));
+///
+/// let from_str = generate_uuid(UuidVersion::V5, Some(Uuid::NAMESPACE_DNS), Some("example.com")).unwrap();
+/// let from_bytes = generate_uuid_bytes(UuidVersion::V5, Some(Uuid::NAMESPACE_DNS), Some(&b"example.com"[..])).unwrap();
+/// assert_eq!(from_str, from_bytes);
+/// ```
+///
+/// # Errors
+///
+/// Returns `GenrsError::MissingNamespace` or `GenrsError::MissingName` if the required parameters
+/// for UUID V3 or V5 are missing.
+#[cfg(feature = "std")]
+pub fn generate_uuid_bytes(version: UuidVersion, namespace: Option<Uuid>, name: Option<&[u8]>) -> Result<Uuid, GenrsError> {
+    match version {
+        UuidVersion::V1 | UuidVersion::V6 => generate_time_uuid(version, None, None),
+        UuidVersion::V3 => {
+            let namespace = namespace.ok_or(GenrsError::MissingNamespace("V3"))?;
+            let name = name.ok_or(GenrsError::MissingName("V3"))?;
+            Ok(Uuid::new_v3(&namespace, name))
+        }
+        UuidVersion::V4 => Ok(Uuid::new_v4()),
+        UuidVersion::V5 => {
+            let namespace = namespace.ok_or(GenrsError::MissingNamespace("V5"))?;
+            let name = name.ok_or(GenrsError::MissingName("V5"))?;
+            Ok(Uuid::new_v5(&namespace, name))
         }
+        UuidVersion::V7 => Ok(Uuid::now_v7()),
+        UuidVersion::V8(custom) => Ok(Uuid::new_v8(custom)),
+        UuidVersion::Nil => Ok(Uuid::nil()),
+        UuidVersion::Max => Ok(Uuid::max()),
+    }
+}
+
+/// JavaScript bindings for WebAssembly builds, exported with `wasm-bindgen`.
+///
+/// Build with `wasm-pack build --no-default-features --features wasm` and call the functions
+/// from JavaScript:
+///
+/// ```js
+/// import { generateKey, generateUuid } from "genrs";
+///
+/// const key = generateKey(32, "base64");
+/// const uuid = generateUuid("v7");
+/// ```
+#[cfg(feature = "wasm")]
+pub mod wasm {
+    use wasm_bindgen::prelude::*;
+
+    use crate::{encode_key, parse_namespace, try_generate_key, EncodingFormat, UuidVersion};
+
+    /// Generates a random key of `length` bytes, encoded in the given format (e.g. `"hex"`).
+    #[wasm_bindgen(js_name = generateKey)]
+    pub fn generate_key(length: usize, format: &str) -> Result<String, JsError> {
+        let format: EncodingFormat = format.parse()?;
+        Ok(encode_key(try_generate_key(length)?, format)?)
+    }
+
+    /// Generates a UUID of the given version (e.g. `"v4"`); V3 and V5 require a namespace and name.
+    #[wasm_bindgen(js_name = generateUuid)]
+    pub fn generate_uuid(version: &str, namespace: Option<String>, name: Option<String>) -> Result<String, JsError> {
+        let version: UuidVersion = version.parse()?;
+        let namespace = namespace.as_deref().map(parse_namespace).transpose()?;
+        Ok(crate::generate_uuid(version, namespace, name.as_deref())?.to_string())
     }
 }